@@ -0,0 +1,8 @@
+# coding=utf-8
+from app import app
+from flask import Response
+
+
+@app.route("/abort-on-error")
+def abort_on_error():
+    return Response("", status=200)
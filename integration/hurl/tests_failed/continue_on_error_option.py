@@ -0,0 +1,8 @@
+# coding=utf-8
+from app import app
+from flask import Response
+
+
+@app.route("/continue-on-error-option")
+def continue_on_error_option():
+    return Response("", status=200)
@@ -0,0 +1,8 @@
+# coding=utf-8
+from app import app
+from flask import Response
+
+
+@app.route("/fail-with-body")
+def fail_with_body():
+    return Response('{"error":"not found"}', status=404, mimetype="application/json")
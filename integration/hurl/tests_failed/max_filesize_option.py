@@ -0,0 +1,8 @@
+# coding=utf-8
+from app import app
+from flask import Response
+
+
+@app.route("/max-filesize")
+def max_filesize():
+    return Response("x" * 2048, mimetype="text/plain")
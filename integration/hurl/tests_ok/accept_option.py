@@ -0,0 +1,8 @@
+from flask import Response, request
+from app import app
+
+
+@app.route("/accept-option")
+def accept_option():
+    assert request.headers.getlist("Accept") == ["application/json"]
+    return Response('{"name":"Bob"}', mimetype="application/json")
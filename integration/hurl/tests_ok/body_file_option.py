@@ -0,0 +1,9 @@
+from flask import request
+from app import app
+
+
+@app.route("/body-file", methods=["POST"])
+def body_file():
+    assert request.headers["Content-Type"] == "application/json"
+    assert request.data == b'{"name":"Bob"}'
+    return ""
@@ -0,0 +1,9 @@
+from flask import request
+from app import app
+
+
+@app.route("/content-type-option", methods=["POST"])
+def content_type_option():
+    assert request.headers.getlist("Content-Type") == ["text/csv"]
+    assert request.data == b"name,age\nBob,42\n"
+    return ""
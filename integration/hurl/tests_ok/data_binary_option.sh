@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/data_binary_option.hurl --variable filename=data.bin --verbose
@@ -0,0 +1,14 @@
+from app import app
+from flask import request
+
+
+@app.route("/form-option", methods=["POST"])
+def form_option():
+    assert request.form["key1"] == "value1"
+
+    upload1 = request.files["upload1"]
+    assert upload1.filename == "data.txt"
+    assert upload1.content_type == "text/plain"
+    assert upload1.read() == b"Hello World!"
+
+    return ""
@@ -0,0 +1,10 @@
+from flask import request
+from app import app
+
+
+@app.route("/json-option", methods=["POST"])
+def json_option():
+    assert request.headers["Content-Type"] == "application/json"
+    assert request.headers["Accept"] == "application/json"
+    assert request.data == b'{"name":"Bob"}'
+    return ""
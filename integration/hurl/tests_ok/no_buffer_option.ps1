@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/no_buffer_option.hurl 2>&1 | Select-String "chunk [0-9]" | ForEach-Object { "$_" }
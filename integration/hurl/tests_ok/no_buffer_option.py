@@ -0,0 +1,14 @@
+import time
+
+from app import app
+from flask import Response
+
+
+@app.route("/no-buffer")
+def no_buffer():
+    def generate():
+        for i in range(1, 4):
+            yield f"chunk{i}"
+            time.sleep(0.2)
+
+    return Response(generate(), mimetype="text/plain")
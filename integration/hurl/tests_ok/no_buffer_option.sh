@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/no_buffer_option.hurl 2>&1 | grep "chunk [0-9]"
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --no-output tests_ok/no_buffer_output.hurl 2>&1 | Select-String "chunk [0-9]|^event|Response: \(received" | ForEach-Object { "$_" }
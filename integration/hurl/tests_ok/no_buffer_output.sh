@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --no-output tests_ok/no_buffer_output.hurl 2>&1 | grep -E "chunk [0-9]|^event|Response: \(received"
@@ -0,0 +1,8 @@
+from flask import request
+from app import app
+
+
+@app.route("/a/../b")
+def path_as_is_option():
+    assert request.path == "/a/../b"
+    return ""
@@ -0,0 +1,10 @@
+from flask import request
+from app import app
+
+
+@app.route("/query-option")
+def query_option():
+    assert request.args.get("sort") == "asc"
+    assert request.args.get("page") == "2"
+    assert request.args.get("q") == "Bob & co"
+    return ""
@@ -0,0 +1,22 @@
+from app import app
+
+count = 0
+
+
+@app.route("/repeat/reset")
+def repeat_reset():
+    global count
+    count = 0
+    return ""
+
+
+@app.route("/repeat/hit")
+def repeat_hit():
+    global count
+    count += 1
+    return ""
+
+
+@app.route("/repeat/count")
+def repeat_count():
+    return str(count)
@@ -0,0 +1,8 @@
+from flask import request
+from app import app
+
+
+@app.route("/request-option", methods=["PROPFIND"])
+def request_option():
+    assert request.method == "PROPFIND"
+    return "", 207
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/url-option")
+def url_option():
+    return "Hello"
@@ -0,0 +1,7 @@
+from app import app
+from flask import request
+
+
+@app.route("/variable-local-option")
+def variable_local_option():
+    return request.args.get("id")
@@ -15,25 +15,30 @@
  * limitations under the License.
  *
  */
+use std::cell::{Cell, RefCell};
 use std::str;
 use std::str::FromStr;
 
 use base64::engine::general_purpose;
 use base64::Engine;
 use chrono::Utc;
-use curl::easy::{List, NetRc, SslOpt};
+use curl::easy::{List, NetRc, SslOpt, SslVersion};
 use curl::{easy, Version};
 use encoding::all::ISO_8859_1;
 use encoding::{DecoderTrap, Encoding};
+use hurl_core::ast::{CertType, ContentLength, TlsVersion, TraceMode};
 use url::Url;
 
 use crate::http::certificate::Certificate;
 use crate::http::core::*;
-use crate::http::debug::log_body;
+use crate::http::debug::{log_body, log_trace};
 use crate::http::header::{
-    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, EXPECT, LOCATION, USER_AGENT,
+    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, EXPECT, LOCATION,
+    USER_AGENT,
+};
+use crate::http::options::{
+    ClientOptions, POST_REDIRECT_301, POST_REDIRECT_302, POST_REDIRECT_303,
 };
-use crate::http::options::ClientOptions;
 use crate::http::request::*;
 use crate::http::request_spec::*;
 use crate::http::response::*;
@@ -42,6 +47,7 @@ use crate::http::{easy_ext, Call, Header, HttpError, Verbosity};
 use crate::runner::Output;
 use crate::util::logger::Logger;
 use crate::util::path::ContextDir;
+use crate::util::term::Stdout;
 
 /// Defines an HTTP client to execute HTTP requests.
 ///
@@ -97,10 +103,14 @@ impl Client {
     }
 
     /// Executes an HTTP request `request_spec`, optionally follows redirection and returns a list of [`Call`].
+    ///
+    /// With `no_buffer`, the body of the last response is written to the optional `stdout` as it's
+    /// received.
     pub fn execute_with_redirect(
         &mut self,
         request_spec: &RequestSpec,
         options: &ClientOptions,
+        mut stdout: Option<&mut Stdout>,
         logger: &mut Logger,
     ) -> Result<Vec<Call>, HttpError> {
         let mut calls = vec![];
@@ -112,7 +122,7 @@ impl Client {
         // Hurl needs to keep everything.
         let mut redirect_count = 0;
         loop {
-            let call = self.execute(&request_spec, options, logger)?;
+            let call = self.execute(&request_spec, options, stdout.as_deref_mut(), logger)?;
             let base_url = call.request.base_url()?;
             let redirect_url = self.get_follow_location(&call.response, &base_url);
             let status = call.response.status;
@@ -130,7 +140,8 @@ impl Client {
                     return Err(HttpError::TooManyRedirect);
                 }
             }
-            let redirect_method = get_redirect_method(status, request_spec.method);
+            let redirect_method =
+                get_redirect_method(status, request_spec.method, options.post_redirect);
             let headers = if options.follow_location_trusted {
                 request_spec.headers
             } else {
@@ -149,10 +160,14 @@ impl Client {
 
     /// Executes an HTTP request `request_spec`, without following redirection and returns a
     /// pair of [`Call`].
+    ///
+    /// With `no_buffer`, the response body is written to the optional `stdout` as it's received,
+    /// unless the response is a redirection to follow.
     pub fn execute(
         &mut self,
         request_spec: &RequestSpec,
         options: &ClientOptions,
+        mut stdout: Option<&mut Stdout>,
         logger: &mut Logger,
     ) -> Result<Call, HttpError> {
         // The handle can be mutated in this function: to start from a clean state, we reset it
@@ -164,9 +179,12 @@ impl Client {
         let start = Utc::now();
         let verbose = options.verbosity.is_some();
         let very_verbose = options.verbosity == Some(Verbosity::VeryVerbose);
+        let trace_body = options.trace_body;
+        let log_bodies = very_verbose && trace_body != Some(TraceMode::Off);
         let mut request_headers = HeaderVec::new();
         let mut status_lines = vec![];
         let mut response_headers = vec![];
+        let response_headers_size = Cell::new(0_u64);
         let has_body_data = !request_spec.body.bytes().is_empty()
             || !request_spec.form.is_empty()
             || !request_spec.multipart.is_empty();
@@ -177,67 +195,95 @@ impl Client {
         // of key-value.
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
+        // With `no_buffer`, the response body chunks are processed as soon as they're received, so
+        // the logger is shared between the debug and the write callbacks.
+        let mut chunk_count = 0;
+        let shared_logger = RefCell::new(&mut *logger);
+        // Bodies of redirections followed by the client are not written to `stdout`.
+        let status_code = Cell::new(0_u32);
+        let is_redirect = Cell::new(false);
 
         {
             let mut transfer = self.handle.transfer();
 
-            transfer.debug_function(|info_type, data| match info_type {
-                // Return all request headers (not one by one)
-                easy::InfoType::HeaderOut => {
-                    let lines = split_lines(data);
-                    // Extracts request headers from libcurl debug info.
-                    // First line is method/path/version line, last line is empty
-                    for line in &lines[1..lines.len() - 1] {
-                        if let Some(header) = Header::parse(line) {
-                            request_headers.push(header);
+            transfer.debug_function(|info_type, data| {
+                let mut logger = shared_logger.borrow_mut();
+                let logger: &mut Logger = &mut logger;
+                match info_type {
+                    // Return all request headers (not one by one)
+                    easy::InfoType::HeaderOut => {
+                        let lines = split_lines(data);
+                        // Extracts request headers from libcurl debug info.
+                        // First line is method/path/version line, last line is empty
+                        for line in &lines[1..lines.len() - 1] {
+                            if let Some(header) = Header::parse(line) {
+                                request_headers.push(header);
+                            }
                         }
-                    }
 
-                    // Logs method, version and request headers now.
-                    if verbose {
-                        logger.debug_method_version_out(&lines[0]);
-                        let headers = request_headers
-                            .iter()
-                            .map(|h| (h.name.as_str(), h.value.as_str()))
-                            .collect::<Vec<_>>();
-                        logger.debug_headers_out(&headers);
-                    }
+                        // Logs method, version and request headers now.
+                        if verbose {
+                            logger.debug_method_version_out(&lines[0]);
+                            let headers = request_headers
+                                .iter()
+                                .map(|h| (h.name.as_str(), h.value.as_str()))
+                                .collect::<Vec<_>>();
+                            logger.debug_headers_out(&headers);
+                        }
 
-                    // If we don't send any data, we log an empty body here instead of relying on
-                    // libcurl computing body in `easy::InfoType::DataOut` because libcurl doesn't
-                    // call `easy::InfoType::DataOut` if there is no data to send.
-                    if !has_body_data && very_verbose {
-                        logger.debug_important("Request body:");
-                        log_body(&[], &request_headers, true, logger);
+                        // If we don't send any data, we log an empty body here instead of relying on
+                        // libcurl computing body in `easy::InfoType::DataOut` because libcurl doesn't
+                        // call `easy::InfoType::DataOut` if there is no data to send.
+                        if !has_body_data && log_bodies {
+                            logger.debug_important("Request body:");
+                            log_body(&[], &request_headers, true, logger);
+                        }
                     }
-                }
-                // We use this callback to get the real body bytes sent by libcurl and logs request
-                // body chunks.
-                easy::InfoType::DataOut => {
-                    if very_verbose {
-                        logger.debug_important("Request body:");
-                        log_body(data, &request_headers, true, logger);
+                    // We use this callback to get the real body bytes sent by libcurl and logs request
+                    // body chunks.
+                    easy::InfoType::DataOut => {
+                        if log_bodies {
+                            logger.debug_important("Request body:");
+                            match trace_body {
+                                Some(mode) => log_trace(data, mode, true, logger),
+                                None => log_body(data, &request_headers, true, logger),
+                            }
+                        }
+                        // Constructs request body from libcurl debug info.
+                        request_body.extend(data);
                     }
-                    // Constructs request body from libcurl debug info.
-                    request_body.extend(data);
-                }
-                // Curl debug logs
-                easy::InfoType::Text => {
-                    let len = data.len();
-                    if very_verbose && len > 0 {
-                        let text = str::from_utf8(&data[..len - 1]);
-                        if let Ok(text) = text {
-                            logger.debug_curl(text);
+                    // Curl debug logs
+                    easy::InfoType::Text => {
+                        let len = data.len();
+                        if very_verbose && len > 0 {
+                            let text = str::from_utf8(&data[..len - 1]);
+                            if let Ok(text) = text {
+                                logger.debug_curl(text);
+                            }
                         }
                     }
+                    _ => {}
                 }
-                _ => {}
             })?;
             transfer.header_function(|h| {
+                // Aborts the transfer when the response headers exceed the maximum size.
+                response_headers_size.set(response_headers_size.get() + h.len() as u64);
+                if let Some(max_header_size) = options.max_header_size {
+                    if response_headers_size.get() > max_header_size {
+                        return false;
+                    }
+                }
                 if let Some(s) = decode_header(h) {
                     if s.starts_with("HTTP/") {
+                        let code = s.split_whitespace().nth(1).and_then(|c| c.parse().ok());
+                        status_code.set(code.unwrap_or_default());
+                        is_redirect.set(false);
                         status_lines.push(s);
                     } else {
+                        let is_location = s.to_lowercase().starts_with("location:");
+                        if is_location && (300..400).contains(&status_code.get()) {
+                            is_redirect.set(options.follow_location);
+                        }
                         response_headers.push(s);
                     }
                 }
@@ -245,11 +291,39 @@ impl Client {
             })?;
 
             transfer.write_function(|data| {
+                // Each chunk is logged as soon as it's received, not once the transfer is done.
+                if options.no_buffer && verbose {
+                    chunk_count += 1;
+                    let size = data.len();
+                    let elapsed = (Utc::now() - start).num_milliseconds() as u64;
+                    let mut logger = shared_logger.borrow_mut();
+                    logger.debug(&format!(
+                        "chunk {chunk_count}: {size} bytes after {elapsed} ms"
+                    ));
+                }
+                if let Some(stdout) = stdout.as_deref_mut() {
+                    if options.no_buffer && !is_redirect.get() {
+                        // Returning less bytes than received aborts the transfer.
+                        if stdout.write_all(data).and_then(|_| stdout.flush()).is_err() {
+                            return Ok(0);
+                        }
+                    }
+                }
                 response_body.extend(data);
                 Ok(data.len())
             })?;
 
             if let Err(e) = transfer.perform() {
+                if let Some(max_header_size) = options.max_header_size {
+                    if response_headers_size.get() > max_header_size {
+                        return Err(HttpError::ResponseHeadersTooLarge { max_header_size });
+                    }
+                }
+                if let Some(max_filesize) = options.max_filesize {
+                    if e.is_filesize_exceeded() {
+                        return Err(HttpError::ResponseBodyTooLarge { max_filesize });
+                    }
+                }
                 let code = e.code() as i32; // due to windows build
                 let description = match e.extra_description() {
                     None => e.description().to_string(),
@@ -258,6 +332,7 @@ impl Client {
                 return Err(HttpError::Libcurl { code, description });
             }
         }
+        let logger = shared_logger.into_inner();
 
         let status = self.handle.response_code()?;
         // TODO: explain why status_lines is Vec ?
@@ -317,10 +392,15 @@ impl Client {
                 .collect::<Vec<_>>();
             logger.debug_headers_in(&headers);
 
-            if very_verbose {
+            if log_bodies {
                 logger.debug_important("Response body:");
-                response.log_body(true, logger);
+                match trace_body {
+                    Some(mode) => log_trace(&response.body, mode, true, logger),
+                    None => response.log_body(true, logger),
+                }
                 logger.debug("");
+            }
+            if very_verbose {
                 timings.log(logger);
             }
         }
@@ -409,19 +489,42 @@ impl Client {
                     self.handle.ssl_cert(cert)?;
                 }
             }
-            self.handle.ssl_cert_type("PEM")?;
+            let cert_type = options.client_cert_type.unwrap_or(CertType::Pem);
+            self.handle.ssl_cert_type(&cert_type.to_string())?;
         }
         if let Some(client_key_file) = options.client_key_file.clone() {
             self.handle.ssl_key(client_key_file)?;
-            self.handle.ssl_cert_type("PEM")?;
+            let key_type = options.client_key_type.unwrap_or(CertType::Pem);
+            self.handle.ssl_key_type(&key_type.to_string())?;
+        }
+        if let Some(client_key_password) = &options.client_key_password {
+            self.handle.key_password(client_key_password)?;
         }
         self.handle.path_as_is(options.path_as_is)?;
         if let Some(proxy) = options.proxy.clone() {
             self.handle.proxy(proxy.as_str())?;
         }
+        if let Some(proxy_user) = &options.proxy_user {
+            let (username, password) = proxy_user.split_once(':').unwrap_or((proxy_user, ""));
+            self.handle.proxy_username(username)?;
+            self.handle.proxy_password(password)?;
+        }
+        if options.proxy_insecure {
+            self.handle.proxy_ssl_verify_peer(false)?;
+            self.handle.proxy_ssl_verify_host(false)?;
+        }
+        if let Some(proxy_cacert_file) = options.proxy_cacert_file.clone() {
+            self.handle.proxy_cainfo(&proxy_cacert_file)?;
+        }
         if let Some(s) = options.no_proxy.clone() {
             self.handle.noproxy(s.as_str())?;
         }
+        if let Some(range) = &options.range {
+            self.handle.range(range)?;
+        }
+        if let Some(referer) = &options.referer {
+            self.handle.referer(referer)?;
+        }
         if let Some(unix_socket) = &options.unix_socket {
             self.handle.unix_socket(unix_socket)?;
         }
@@ -439,11 +542,79 @@ impl Client {
         }
         self.handle.timeout(options.timeout)?;
         self.handle.connect_timeout(options.connect_timeout)?;
+        if let Some(dns_cache_ttl) = options.dns_cache_ttl {
+            self.handle.dns_cache_timeout(dns_cache_ttl)?;
+        }
+        if let Some(max_filesize) = options.max_filesize {
+            self.handle.max_filesize(max_filesize)?;
+        }
+        if let Some(expect_100_timeout) = options.expect_100_timeout {
+            easy_ext::expect_100_timeout(&mut self.handle, expect_100_timeout)?;
+        }
+        if let Some(happy_eyeballs_timeout) = options.happy_eyeballs_timeout {
+            easy_ext::happy_eyeballs_timeout(&mut self.handle, happy_eyeballs_timeout)?;
+        }
+        if options.haproxy_protocol {
+            easy_ext::haproxy_protocol(&mut self.handle, true)?;
+        }
+        if let Some(limit_rate) = options.limit_rate {
+            self.handle.max_send_speed(limit_rate)?;
+            self.handle.max_recv_speed(limit_rate)?;
+        }
+        if let Some(speed_limit) = options.speed_limit {
+            self.handle.low_speed_limit(speed_limit)?;
+        }
+        if let Some(speed_time) = options.speed_time {
+            self.handle.low_speed_time(speed_time)?;
+        }
+        self.handle.tcp_keepalive(options.tcp_keepalive)?;
+        if let Some(tcp_keepidle) = options.tcp_keepidle {
+            self.handle.tcp_keepidle(tcp_keepidle)?;
+        }
+        if let Some(tcp_keepintvl) = options.tcp_keepintvl {
+            self.handle.tcp_keepintvl(tcp_keepintvl)?;
+        }
+        self.handle.tcp_nodelay(options.tcp_nodelay)?;
+        if options.fresh_connect {
+            self.handle.fresh_connect(true)?;
+            self.handle.forbid_reuse(true)?;
+        }
 
         self.set_ssl_options(options.ssl_no_revoke)?;
+        if let Some(tls_max) = options.tls_max {
+            let max_version = match tls_max {
+                TlsVersion::Tls10 => SslVersion::Tlsv10,
+                TlsVersion::Tls11 => SslVersion::Tlsv11,
+                TlsVersion::Tls12 => SslVersion::Tlsv12,
+                TlsVersion::Tls13 => SslVersion::Tlsv13,
+            };
+            self.handle
+                .ssl_min_max_version(SslVersion::Default, max_version)?;
+        }
 
         let url = self.generate_url(&request_spec.url, &request_spec.querystring);
         self.handle.url(url.as_str())?;
+        // libcurl strips the fragment of the URL, we send it through a custom request target.
+        if options.send_fragment {
+            if let Some(request_target) = request_target(&url) {
+                easy_ext::request_target(&mut self.handle, &request_target)?;
+            }
+        }
+        // SSH keys are only used by SFTP and SCP transfers.
+        if url.starts_with("sftp://") || url.starts_with("scp://") {
+            if let Some(ssh_public_key_file) = &options.ssh_public_key_file {
+                easy_ext::ssh_public_key_file(&mut self.handle, ssh_public_key_file)?;
+            }
+            if let Some(ssh_private_key_file) = &options.ssh_private_key_file {
+                easy_ext::ssh_private_key_file(&mut self.handle, ssh_private_key_file)?;
+            }
+        }
+        // Login options are only used by mail protocols (IMAP, POP3 and SMTP).
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            if let Some(login_options) = &options.login_options {
+                easy_ext::login_options(&mut self.handle, login_options)?;
+            }
+        }
         let method = &request_spec.method;
         self.set_method(method)?;
         self.set_cookies(&request_spec.cookies)?;
@@ -518,6 +689,16 @@ impl Client {
             }
         }
 
+        // If request has no Content-Length header, we remove or override the one computed by curl
+        // according to the content-length option.
+        if !request_spec.headers.contains_key(CONTENT_LENGTH) {
+            match options.content_length {
+                ContentLength::Auto => {}
+                ContentLength::Omit => list.append(&format!("{}:", CONTENT_LENGTH))?,
+                ContentLength::Fixed(n) => list.append(&format!("{}: {n}", CONTENT_LENGTH))?,
+            }
+        }
+
         // Workaround for libcurl issue #11664: When Hurl explicitly sets `Expect:` to remove the header,
         // libcurl will generate `SignedHeaders` that include `expect` even though the header is not
         // present, causing some APIs to reject the request.
@@ -745,6 +926,12 @@ impl Client {
             ));
         }
         arguments.append(&mut options.curl_args());
+        if options.send_fragment {
+            if let Some(request_target) = request_target(url.trim_matches('\'')) {
+                arguments.push("--request-target".to_string());
+                arguments.push(format!("'{request_target}'"));
+            }
+        }
 
         // --output is not an option of the HTTP client, we deal with it here:
         match output {
@@ -765,6 +952,18 @@ impl Client {
     }
 }
 
+/// Returns the request target of `url` including its fragment (`/hello?a=b#world`), or `None` if
+/// `url` has no fragment.
+fn request_target(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let fragment = url.fragment()?;
+    let query = match url.query() {
+        Some(query) => format!("?{query}"),
+        None => String::new(),
+    };
+    Some(format!("{}{query}#{fragment}", url.path()))
+}
+
 /// Returns the redirect url.
 fn get_redirect_url(location: &str, base_url: &str) -> String {
     if location.starts_with('/') {
@@ -775,9 +974,23 @@ fn get_redirect_url(location: &str, base_url: &str) -> String {
 }
 
 /// Returns the method used for redirecting a request/response with `response_status`.
-fn get_redirect_method(response_status: u32, original_method: Method) -> Method {
+///
+/// `post_redirect` is a bitmask of [`POST_REDIRECT_301`], [`POST_REDIRECT_302`] and
+/// [`POST_REDIRECT_303`] keeping POST requests as POST for the corresponding status.
+fn get_redirect_method(
+    response_status: u32,
+    original_method: Method,
+    post_redirect: u32,
+) -> Method {
     // This replicates curl's behavior
+    let keep_post = match response_status {
+        301 => post_redirect & POST_REDIRECT_301 != 0,
+        302 => post_redirect & POST_REDIRECT_302 != 0,
+        303 => post_redirect & POST_REDIRECT_303 != 0,
+        _ => false,
+    };
     match response_status {
+        301..=303 if keep_post && original_method.0 == "POST" => original_method,
         301..=303 => Method("GET".to_string()),
         // Could be only 307 and 308, but curl does this for all 3xx
         // codes not converted to GET above.
@@ -1037,7 +1250,23 @@ mod tests {
         ];
         for (status, original, redirected) in data {
             assert_eq!(
-                get_redirect_method(status, Method(original.to_string())),
+                get_redirect_method(status, Method(original.to_string()), 0),
+                Method(redirected.to_string())
+            );
+        }
+
+        // With POST redirections bitmask
+        let data = [
+            (301, "POST", POST_REDIRECT_301, "POST"),
+            (301, "DELETE", POST_REDIRECT_301, "GET"),
+            (301, "POST", POST_REDIRECT_302, "GET"),
+            (302, "POST", POST_REDIRECT_302 | POST_REDIRECT_303, "POST"),
+            (303, "POST", POST_REDIRECT_302 | POST_REDIRECT_303, "POST"),
+            (303, "POST", POST_REDIRECT_301, "GET"),
+        ];
+        for (status, original, post_redirect, redirected) in data {
+            assert_eq!(
+                get_redirect_method(status, Method(original.to_string()), post_redirect),
                 Method(redirected.to_string())
             );
         }
@@ -1151,6 +1380,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_request_target() {
+        assert_eq!(
+            request_target("http://localhost:8000/hello#world"),
+            Some("/hello#world".to_string())
+        );
+        assert_eq!(
+            request_target("http://localhost:8000/hello?a=b#world"),
+            Some("/hello?a=b#world".to_string())
+        );
+        assert_eq!(
+            request_target("http://localhost:8000#world"),
+            Some("/#world".to_string())
+        );
+        assert_eq!(request_target("http://localhost:8000/hello"), None);
+    }
+
     #[test]
     fn parse_cert_option() {
         assert_eq!(parse_cert_password("foobar"), ("foobar".to_string(), None));
@@ -18,6 +18,7 @@
 use crate::http::{mimetype, HeaderVec};
 use crate::util::logger::Logger;
 use encoding::DecoderTrap;
+use hurl_core::ast::TraceMode;
 
 /// Logs a buffer of bytes representing an HTTP request or response `body`.
 /// If the body is kind of text, we log all the text lines. If we can't detect that this is a text
@@ -85,3 +86,62 @@ pub fn log_bytes(bytes: &[u8], max: usize, debug: bool, logger: &mut Logger) {
         logger.info(&log);
     }
 }
+
+/// Logs a buffer of bytes `body` with a trace `mode`: as ASCII text (non printable bytes are
+/// replaced by `.`) or as hexadecimal bytes, 16 bytes per line. Nothing is logged with
+/// [`TraceMode::Off`].
+pub fn log_trace(body: &[u8], mode: TraceMode, debug: bool, logger: &mut Logger) {
+    match mode {
+        TraceMode::Off => {}
+        TraceMode::Ascii => log_text(&to_ascii(body), debug, logger),
+        TraceMode::Hex => log_text(&to_hex(body), debug, logger),
+    }
+}
+
+fn to_ascii(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| match b {
+            b'\n' | 0x20..=0x7e => *b as char,
+            _ => '.',
+        })
+        .collect()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let hex = chunk
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!("{:04x}: {hex}", i * 16)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(to_ascii(b""), "");
+        assert_eq!(to_ascii(b"Hello\nWorld"), "Hello\nWorld");
+        assert_eq!(to_ascii(&[0x47, 0x00, 0xff, 0x0d, 0x49]), "G...I");
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(to_hex(b""), "");
+        assert_eq!(to_hex(b"Hi\n"), "0000: 48 69 0a");
+        assert_eq!(
+            to_hex(b"0123456789abcdefXY"),
+            "0000: 30 31 32 33 34 35 36 37 38 39 61 62 63 64 65 66\n0010: 58 59"
+        );
+    }
+}
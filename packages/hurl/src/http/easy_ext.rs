@@ -16,12 +16,17 @@
  *
  */
 use std::ffi::{CStr, CString};
+use std::os::raw::c_long;
 use std::ptr;
 use std::time::Duration;
 
 use curl::easy::Easy;
 use curl::Error;
-use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLINFO, CURLOPT_NETRC_FILE};
+use curl_sys::{
+    curl_certinfo, curl_off_t, curl_slist, CURLoption, CURLINFO, CURLOPTTYPE_LONG,
+    CURLOPTTYPE_OBJECTPOINT, CURLOPT_EXPECT_100_TIMEOUT_MS, CURLOPT_NETRC_FILE,
+    CURLOPT_SSH_PRIVATE_KEYFILE, CURLOPT_SSH_PUBLIC_KEYFILE,
+};
 
 /// Some definitions not present in curl-sys
 const CURLINFO_OFF_T: CURLINFO = 0x600000;
@@ -33,6 +38,11 @@ const CURLINFO_PRETRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 53;
 const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 
+const CURLOPT_HAPPY_EYEBALLS_TIMEOUT_MS: CURLoption = CURLOPTTYPE_LONG + 271;
+const CURLOPT_HAPROXYPROTOCOL: CURLoption = CURLOPTTYPE_LONG + 274;
+const CURLOPT_LOGIN_OPTIONS: CURLoption = CURLOPTTYPE_OBJECTPOINT + 224;
+const CURLOPT_REQUEST_TARGET: CURLoption = CURLOPTTYPE_OBJECTPOINT + 266;
+
 /// Represents certificate information.
 /// `data` has format "name:content";
 #[derive(Clone)]
@@ -180,6 +190,62 @@ pub fn netrc_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
     })
 }
 
+/// Sets the time to wait for a `100-continue` response before sending the request body.
+pub fn expect_100_timeout(easy: &mut Easy, timeout: Duration) -> Result<(), Error> {
+    let timeout = timeout.as_millis() as c_long;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_EXPECT_100_TIMEOUT_MS, timeout)
+    })
+}
+
+/// Sets the head start given to IPv6 over IPv4 when connecting to dual-stack hosts.
+pub fn happy_eyeballs_timeout(easy: &mut Easy, timeout: Duration) -> Result<(), Error> {
+    let timeout = timeout.as_millis() as c_long;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_HAPPY_EYEBALLS_TIMEOUT_MS, timeout)
+    })
+}
+
+/// Sends a HAProxy PROXY protocol v1 header at the beginning of the connection.
+pub fn haproxy_protocol(easy: &mut Easy, enable: bool) -> Result<(), Error> {
+    let enable = enable as c_long;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_HAPROXYPROTOCOL, enable)
+    })
+}
+
+/// Sets the public key file used for SSH authentication.
+pub fn ssh_public_key_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
+    let filename = CString::new(filename)?;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_SSH_PUBLIC_KEYFILE, filename.as_ptr())
+    })
+}
+
+/// Sets the private key file used for SSH authentication.
+pub fn ssh_private_key_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
+    let filename = CString::new(filename)?;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_SSH_PRIVATE_KEYFILE, filename.as_ptr())
+    })
+}
+
+/// Sets the protocol specific login options (IMAP, LDAP, POP3 and SMTP), like `AUTH=PLAIN`.
+pub fn login_options(easy: &mut Easy, options: &str) -> Result<(), Error> {
+    let options = CString::new(options)?;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_LOGIN_OPTIONS, options.as_ptr())
+    })
+}
+
+/// Uses `target` as the request target of the request line, instead of the one of the URL.
+pub fn request_target(easy: &mut Easy, target: &str) -> Result<(), Error> {
+    let target = CString::new(target)?;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_REQUEST_TARGET, target.as_ptr())
+    })
+}
+
 /// Converts an instance of libcurl linked list [`curl_slist`] to a vec of [`String`].
 fn to_list(slist: *mut curl_slist) -> Vec<String> {
     let mut data = vec![];
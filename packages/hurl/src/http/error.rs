@@ -38,6 +38,12 @@ pub enum HttpError {
         option: String,
         minimum_version: String,
     },
+    ResponseBodyTooLarge {
+        max_filesize: u64,
+    },
+    ResponseHeadersTooLarge {
+        max_header_size: u64,
+    },
     StatuslineIsMissing,
     TooManyRedirect,
     UnsupportedContentEncoding {
@@ -48,6 +54,15 @@ pub enum HttpError {
     InvalidUrlPrefix(String),
 }
 
+impl HttpError {
+    /// Returns `true` if this error occurs while resolving or connecting to the host.
+    pub fn is_connection_error(&self) -> bool {
+        // See <https://curl.se/libcurl/c/libcurl-errors.html>
+        // 6: CURLE_COULDNT_RESOLVE_HOST, 7: CURLE_COULDNT_CONNECT
+        matches!(self, HttpError::Libcurl { code: 6 | 7, .. })
+    }
+}
+
 impl From<curl::Error> for HttpError {
     fn from(err: curl::Error) -> Self {
         let code = err.code() as i32;
@@ -18,12 +18,16 @@
 use core::fmt;
 use std::slice::Iter;
 
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept>
+pub const ACCEPT: &str = "Accept";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept-Encoding>
 pub const ACCEPT_ENCODING: &str = "Accept-Encoding";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Authorization>
 pub const AUTHORIZATION: &str = "Authorization";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Cookie>
 pub const COOKIE: &str = "Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Length>
+pub const CONTENT_LENGTH: &str = "Content-Length";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding>
 pub const CONTENT_ENCODING: &str = "Content-Encoding";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Type>
@@ -27,9 +27,12 @@ pub use self::core::Cookie;
 pub(crate) use self::core::{Param, RequestCookie};
 pub(crate) use self::error::HttpError;
 pub use self::header::{
-    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, USER_AGENT,
+    Header, HeaderVec, ACCEPT, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT,
+    USER_AGENT,
+};
+pub(crate) use self::options::{
+    ClientOptions, Verbosity, POST_REDIRECT_301, POST_REDIRECT_302, POST_REDIRECT_303,
 };
-pub(crate) use self::options::{ClientOptions, Verbosity};
 pub use self::request::{IpResolve, Request, RequestedHttpVersion};
 pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
 pub use self::response::{HttpVersion, Response};
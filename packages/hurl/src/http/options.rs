@@ -17,37 +17,76 @@
  */
 use std::time::Duration;
 
-use hurl_core::ast::Retry;
+use hurl_core::ast::{CertType, ContentLength, Retry, TlsVersion, TraceMode};
 
 use crate::http::request::RequestedHttpVersion;
 use crate::http::IpResolve;
 
+/// Keeps POST requests as POST when following a 301 redirect.
+pub const POST_REDIRECT_301: u32 = 1;
+/// Keeps POST requests as POST when following a 302 redirect.
+pub const POST_REDIRECT_302: u32 = 2;
+/// Keeps POST requests as POST when following a 303 redirect.
+pub const POST_REDIRECT_303: u32 = 4;
+
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
     pub aws_sigv4: Option<String>,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
+    pub client_cert_type: Option<CertType>,
     pub client_key_file: Option<String>,
+    pub client_key_type: Option<CertType>,
+    pub client_key_password: Option<String>,
     pub compressed: bool,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
+    pub content_length: ContentLength,
     pub cookie_input_file: Option<String>,
+    pub dns_cache_ttl: Option<Duration>,
+    pub expect_100_timeout: Option<Duration>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
+    pub fresh_connect: bool,
+    pub globoff: bool,
+    pub happy_eyeballs_timeout: Option<Duration>,
+    pub haproxy_protocol: bool,
     pub http_version: RequestedHttpVersion,
     pub insecure: bool,
     pub ip_resolve: IpResolve,
+    pub limit_rate: Option<u64>,
+    pub login_options: Option<String>,
     pub max_redirect: Option<usize>,
+    pub max_header_size: Option<u64>,
+    pub max_filesize: Option<u64>,
     pub netrc: bool,
     pub netrc_file: Option<String>,
     pub netrc_optional: bool,
+    pub no_buffer: bool,
     pub no_proxy: Option<String>,
     pub path_as_is: bool,
+    pub post_redirect: u32,
     pub proxy: Option<String>,
+    pub proxy_user: Option<String>,
+    pub proxy_insecure: bool,
+    pub proxy_cacert_file: Option<String>,
+    pub range: Option<String>,
+    pub referer: Option<String>,
     pub resolves: Vec<String>,
     pub retry: Retry,
+    pub send_fragment: bool,
+    pub ssh_public_key_file: Option<String>,
+    pub ssh_private_key_file: Option<String>,
     pub ssl_no_revoke: bool,
+    pub speed_limit: Option<u32>,
+    pub speed_time: Option<Duration>,
+    pub tcp_keepalive: bool,
+    pub tcp_keepidle: Option<Duration>,
+    pub tcp_keepintvl: Option<Duration>,
+    pub tcp_nodelay: bool,
     pub timeout: Duration,
+    pub tls_max: Option<TlsVersion>,
+    pub trace_body: Option<TraceMode>,
     pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
@@ -67,27 +106,59 @@ impl Default for ClientOptions {
             aws_sigv4: None,
             cacert_file: None,
             client_cert_file: None,
+            client_cert_type: None,
             client_key_file: None,
+            client_key_type: None,
+            client_key_password: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
+            content_length: ContentLength::Auto,
             cookie_input_file: None,
+            dns_cache_ttl: None,
+            expect_100_timeout: None,
             follow_location: false,
             follow_location_trusted: false,
+            fresh_connect: false,
+            globoff: false,
+            happy_eyeballs_timeout: None,
+            haproxy_protocol: false,
             http_version: RequestedHttpVersion::default(),
             insecure: false,
             ip_resolve: IpResolve::default(),
+            limit_rate: None,
+            login_options: None,
             max_redirect: Some(50),
+            max_header_size: None,
+            max_filesize: None,
             netrc: false,
             netrc_file: None,
             netrc_optional: false,
+            no_buffer: false,
             no_proxy: None,
             path_as_is: false,
+            post_redirect: 0,
             proxy: None,
+            proxy_user: None,
+            proxy_insecure: false,
+            proxy_cacert_file: None,
+            range: None,
+            referer: None,
             resolves: vec![],
             retry: Retry::None,
+            send_fragment: false,
+            ssh_public_key_file: None,
+            ssh_private_key_file: None,
             ssl_no_revoke: false,
+            speed_limit: None,
+            speed_time: None,
+            tcp_keepalive: false,
+            tcp_keepidle: None,
+            tcp_keepintvl: None,
+            tcp_nodelay: true,
             timeout: Duration::from_secs(300),
+            tls_max: None,
+            trace_body: None,
             unix_socket: None,
             user: None,
             user_agent: None,
@@ -113,10 +184,22 @@ impl ClientOptions {
             arguments.push("--cert".to_string());
             arguments.push(client_cert_file.clone());
         }
+        if let Some(client_cert_type) = self.client_cert_type {
+            arguments.push("--cert-type".to_string());
+            arguments.push(client_cert_type.to_string());
+        }
         if let Some(ref client_key_file) = self.client_key_file {
             arguments.push("--key".to_string());
             arguments.push(client_key_file.clone());
         }
+        if let Some(client_key_type) = self.client_key_type {
+            arguments.push("--key-type".to_string());
+            arguments.push(client_key_type.to_string());
+        }
+        if let Some(ref client_key_password) = self.client_key_password {
+            arguments.push("--pass".to_string());
+            arguments.push(format!("'{client_key_password}'"));
+        }
         if self.compressed {
             arguments.push("--compressed".to_string());
         }
@@ -128,10 +211,33 @@ impl ClientOptions {
             arguments.push("--connect-to".to_string());
             arguments.push(connect.clone());
         }
+        match self.content_length {
+            ContentLength::Auto => {}
+            ContentLength::Omit => {
+                arguments.push("--header".to_string());
+                arguments.push("'Content-Length:'".to_string());
+            }
+            ContentLength::Fixed(n) => {
+                arguments.push("--header".to_string());
+                arguments.push(format!("'Content-Length: {n}'"));
+            }
+        }
         if let Some(ref cookie_file) = self.cookie_input_file {
             arguments.push("--cookie".to_string());
             arguments.push(cookie_file.clone());
         }
+        if let Some(expect_100_timeout) = self.expect_100_timeout {
+            arguments.push("--expect100-timeout".to_string());
+            arguments.push(expect_100_timeout.as_secs_f64().to_string());
+        }
+        // libcurl never globs URLs, this flag is only used by the curl command line.
+        if self.globoff {
+            arguments.push("--globoff".to_string());
+        }
+        if let Some(happy_eyeballs_timeout) = self.happy_eyeballs_timeout {
+            arguments.push("--happy-eyeballs-timeout-ms".to_string());
+            arguments.push(happy_eyeballs_timeout.as_millis().to_string());
+        }
         match self.http_version {
             RequestedHttpVersion::Default => {}
             RequestedHttpVersion::Http10 => arguments.push("--http1.0".to_string()),
@@ -139,6 +245,9 @@ impl ClientOptions {
             RequestedHttpVersion::Http2 => arguments.push("--http2".to_string()),
             RequestedHttpVersion::Http3 => arguments.push("--http3".to_string()),
         }
+        if self.haproxy_protocol {
+            arguments.push("--haproxy-protocol".to_string());
+        }
         if self.insecure {
             arguments.push("--insecure".to_string());
         }
@@ -147,11 +256,23 @@ impl ClientOptions {
             IpResolve::IpV4 => arguments.push("--ipv4".to_string()),
             IpResolve::IpV6 => arguments.push("--ipv6".to_string()),
         }
+        if let Some(limit_rate) = self.limit_rate {
+            arguments.push("--limit-rate".to_string());
+            arguments.push(limit_rate.to_string());
+        }
+        if let Some(login_options) = &self.login_options {
+            arguments.push("--login-options".to_string());
+            arguments.push(format!("'{login_options}'"));
+        }
         if self.follow_location_trusted {
             arguments.push("--location-trusted".to_string());
         } else if self.follow_location {
             arguments.push("--location".to_string());
         }
+        if let Some(max_filesize) = self.max_filesize {
+            arguments.push("--max-filesize".to_string());
+            arguments.push(max_filesize.to_string());
+        }
         if self.max_redirect != ClientOptions::default().max_redirect {
             let max_redirect = match self.max_redirect {
                 None => -1,
@@ -170,21 +291,83 @@ impl ClientOptions {
         if self.netrc {
             arguments.push("--netrc".to_string());
         }
+        if self.no_buffer {
+            arguments.push("--no-buffer".to_string());
+        }
+        if let Some(ref no_proxy) = self.no_proxy {
+            arguments.push("--noproxy".to_string());
+            arguments.push(format!("'{no_proxy}'"));
+        }
         if self.path_as_is {
             arguments.push("--path-as-is".to_string());
         }
+        if self.post_redirect & POST_REDIRECT_301 != 0 {
+            arguments.push("--post301".to_string());
+        }
+        if self.post_redirect & POST_REDIRECT_302 != 0 {
+            arguments.push("--post302".to_string());
+        }
+        if self.post_redirect & POST_REDIRECT_303 != 0 {
+            arguments.push("--post303".to_string());
+        }
         if let Some(ref proxy) = self.proxy {
             arguments.push("--proxy".to_string());
             arguments.push(format!("'{proxy}'"));
         }
+        if let Some(ref proxy_cacert_file) = self.proxy_cacert_file {
+            arguments.push("--proxy-cacert".to_string());
+            arguments.push(proxy_cacert_file.clone());
+        }
+        if self.proxy_insecure {
+            arguments.push("--proxy-insecure".to_string());
+        }
+        if let Some(ref proxy_user) = self.proxy_user {
+            arguments.push("--proxy-user".to_string());
+            arguments.push(format!("'{proxy_user}'"));
+        }
+        if let Some(ref range) = self.range {
+            arguments.push("--range".to_string());
+            arguments.push(range.clone());
+        }
+        if let Some(ref referer) = self.referer {
+            arguments.push("--referer".to_string());
+            arguments.push(format!("'{referer}'"));
+        }
         for resolve in self.resolves.iter() {
             arguments.push("--resolve".to_string());
             arguments.push(resolve.clone());
         }
+        if let Some(ref ssh_public_key_file) = self.ssh_public_key_file {
+            arguments.push("--pubkey".to_string());
+            arguments.push(ssh_public_key_file.clone());
+        }
+        if let Some(ref ssh_private_key_file) = self.ssh_private_key_file {
+            arguments.push("--key".to_string());
+            arguments.push(ssh_private_key_file.clone());
+        }
+        if let Some(speed_limit) = self.speed_limit {
+            arguments.push("--speed-limit".to_string());
+            arguments.push(speed_limit.to_string());
+        }
+        if let Some(speed_time) = self.speed_time {
+            arguments.push("--speed-time".to_string());
+            arguments.push(speed_time.as_secs().to_string());
+        }
+        if let Some(tcp_keepidle) = self.tcp_keepidle {
+            arguments.push("--keepalive-time".to_string());
+            arguments.push(tcp_keepidle.as_secs().to_string());
+        }
+        if !self.tcp_nodelay {
+            arguments.push("--no-tcp-nodelay".to_string());
+        }
         if self.timeout != ClientOptions::default().timeout {
             arguments.push("--timeout".to_string());
             arguments.push(self.timeout.as_secs().to_string());
         }
+        if let Some(tls_max) = self.tls_max {
+            arguments.push("--tls-max".to_string());
+            arguments.push(tls_max.to_string());
+        }
         if let Some(ref unix_socket) = self.unix_socket {
             arguments.push("--unix-socket".to_string());
             arguments.push(format!("'{unix_socket}'"));
@@ -214,30 +397,62 @@ mod tests {
                 aws_sigv4: None,
                 cacert_file: None,
                 client_cert_file: None,
+                client_cert_type: None,
                 client_key_file: None,
+                client_key_type: None,
+                client_key_password: None,
                 compressed: true,
                 connect_timeout: Duration::from_secs(20),
                 connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
+                content_length: ContentLength::Fixed(512),
                 cookie_input_file: Some("cookie_file".to_string()),
+                dns_cache_ttl: None,
+                expect_100_timeout: Some(Duration::from_millis(1500)),
                 follow_location: true,
                 follow_location_trusted: false,
+                fresh_connect: false,
+                globoff: true,
+                happy_eyeballs_timeout: Some(Duration::from_millis(200)),
+                haproxy_protocol: false,
                 http_version: RequestedHttpVersion::Http10,
                 insecure: true,
                 ip_resolve: IpResolve::IpV6,
+                limit_rate: Some(125000),
+                login_options: None,
                 max_redirect: Some(10),
+                max_header_size: Some(65536),
+                max_filesize: None,
                 netrc: false,
                 netrc_file: Some("/var/run/netrc".to_string()),
                 netrc_optional: true,
+                no_buffer: false,
                 path_as_is: true,
+                post_redirect: 0b101,
                 proxy: Some("localhost:3128".to_string()),
+                proxy_user: None,
+                proxy_insecure: false,
+                proxy_cacert_file: None,
+                range: Some("0-1023".to_string()),
+                referer: Some("http://example.com/page".to_string()),
                 no_proxy: None,
                 resolves: vec![
                     "foo.com:80:192.168.0.1".to_string(),
                     "bar.com:443:127.0.0.1".to_string()
                 ],
                 retry: Retry::None,
+                send_fragment: false,
+                ssh_public_key_file: None,
+                ssh_private_key_file: None,
                 ssl_no_revoke: false,
+                speed_limit: None,
+                speed_time: None,
+                tcp_keepalive: true,
+                tcp_keepidle: Some(Duration::from_secs(60)),
+                tcp_keepintvl: None,
+                tcp_nodelay: false,
                 timeout: Duration::from_secs(10),
+                tls_max: None,
+                trace_body: None,
                 unix_socket: Some("/var/run/example.sock".to_string()),
                 user: Some("user:password".to_string()),
                 user_agent: Some("my-useragent".to_string()),
@@ -250,11 +465,20 @@ mod tests {
                 "20",
                 "--connect-to",
                 "example.com:443:host-47.example.com:443",
+                "--header",
+                "'Content-Length: 512'",
                 "--cookie",
                 "cookie_file",
+                "--expect100-timeout",
+                "1.5",
+                "--globoff",
+                "--happy-eyeballs-timeout-ms",
+                "200",
                 "--http1.0",
                 "--insecure",
                 "--ipv6",
+                "--limit-rate",
+                "125000",
                 "--location",
                 "--max-redirs",
                 "10",
@@ -262,12 +486,21 @@ mod tests {
                 "'/var/run/netrc'",
                 "--netrc-optional",
                 "--path-as-is",
+                "--post301",
+                "--post303",
                 "--proxy",
                 "'localhost:3128'",
+                "--range",
+                "0-1023",
+                "--referer",
+                "'http://example.com/page'",
                 "--resolve",
                 "foo.com:80:192.168.0.1",
                 "--resolve",
                 "bar.com:443:127.0.0.1",
+                "--keepalive-time",
+                "60",
+                "--no-tcp-nodelay",
                 "--timeout",
                 "10",
                 "--unix-socket",
@@ -261,11 +261,19 @@ fn get_summary(runs: &[HurlRun], duration: u128) -> String {
     let success_percent = 100.0 * success as f32 / total as f32;
     let failed = total - success;
     let failed_percent = 100.0 * failed as f32 / total as f32;
+    let skipped: usize = runs.iter().map(|r| r.hurl_result.skipped_entries).sum();
+    // Skipped entries are only reported when there are some, so that the usual summary is kept.
+    let skipped = if skipped > 0 {
+        format!("Skipped entries: {skipped}\n")
+    } else {
+        String::new()
+    };
     format!(
         "--------------------------------------------------------------------------------\n\
              Executed files:  {total}\n\
              Succeeded files: {success} ({success_percent:.1}%)\n\
              Failed files:    {failed} ({failed_percent:.1}%)\n\
+             {skipped}\
              Duration:        {duration} ms\n"
     )
 }
@@ -295,6 +303,7 @@ pub mod tests {
                 filename: Input::new(""),
                 hurl_result: HurlResult {
                     entries: vec![dummy_entry; entries_count],
+                    skipped_entries: 0,
                     time_in_ms: 0,
                     success,
                     cookies: vec![],
@@ -326,5 +335,20 @@ pub mod tests {
             Failed files:    1 (33.3%)\n\
             Duration:        200 ms\n"
         );
+
+        let mut runs = vec![new_run(true, 3), new_run(true, 2)];
+        runs[0].hurl_result.skipped_entries = 1;
+        runs[1].hurl_result.skipped_entries = 2;
+        let duration = 50;
+        let summary = get_summary(&runs, duration);
+        assert_eq!(
+            summary,
+            "--------------------------------------------------------------------------------\n\
+            Executed files:  2\n\
+            Succeeded files: 2 (100.0%)\n\
+            Failed files:    0 (0.0%)\n\
+            Skipped entries: 3\n\
+            Duration:        50 ms\n"
+        );
     }
 }
@@ -158,6 +158,7 @@ mod tests {
                     compressed: false,
                 },
             ],
+            skipped_entries: 0,
             time_in_ms: 100,
             success: true,
             cookies: vec![],
@@ -146,6 +146,7 @@ mod tests {
         let mut testcases = vec![];
         let res = HurlResult {
             entries: vec![],
+            skipped_entries: 0,
             time_in_ms: 230,
             success: true,
             cookies: vec![],
@@ -171,6 +172,7 @@ mod tests {
                 time_in_ms: 0,
                 compressed: false,
             }],
+            skipped_entries: 0,
             time_in_ms: 230,
             success: true,
             cookies: vec![],
@@ -194,6 +196,7 @@ mod tests {
                 time_in_ms: 0,
                 compressed: false,
             }],
+            skipped_entries: 0,
             time_in_ms: 230,
             success: true,
             cookies: vec![],
@@ -94,6 +94,7 @@ mod test {
     fn test_create_testcase_success() {
         let hurl_result = HurlResult {
             entries: vec![],
+            skipped_entries: 0,
             time_in_ms: 230,
             success: true,
             cookies: vec![],
@@ -133,6 +134,7 @@ HTTP/1.0 200
                 time_in_ms: 0,
                 compressed: false,
             }],
+            skipped_entries: 0,
             time_in_ms: 230,
             success: true,
             cookies: vec![],
@@ -172,6 +174,7 @@ HTTP/1.0 200
                 time_in_ms: 0,
                 compressed: false,
             }],
+            skipped_entries: 0,
             time_in_ms: 230,
             success: true,
             cookies: vec![],
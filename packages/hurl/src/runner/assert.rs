@@ -118,14 +118,15 @@ impl AssertResult {
 }
 
 /// Evaluates an explicit `assert`, given a set of `variables`, a HTTP response and a context
-/// directory `context_dir`.
+/// directory `context_dir`. If `raw` is true, the response body is not decompressed.
 pub fn eval_explicit_assert(
     assert: &Assert,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
     context_dir: &ContextDir,
+    raw: bool,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_response);
+    let query_result = eval_query(&assert.query, variables, http_response, raw);
 
     let actual = if assert.filters.is_empty() {
         query_result
@@ -234,7 +235,8 @@ pub mod tests {
                 &assert_count_user(),
                 &variables,
                 &xml_three_users_http_response(),
-                &context_dir
+                &context_dir,
+                false,
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(3)))),
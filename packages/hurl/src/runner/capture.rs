@@ -28,14 +28,16 @@ use crate::runner::template::eval_template;
 use crate::runner::Value;
 
 /// Evaluates a `capture` with `variables` map and `http_response`, returns a
-/// [`CaptureResult`] on success or an [`Error`] .
+/// [`CaptureResult`] on success or an [`Error`]. If `raw` is true, the response body is not
+/// decompressed.
 pub fn eval_capture(
     capture: &Capture,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    raw: bool,
 ) -> Result<CaptureResult, Error> {
     let name = eval_template(&capture.name, variables)?;
-    let value = eval_query(&capture.query, variables, http_response)?;
+    let value = eval_query(&capture.query, variables, http_response, raw)?;
     let value = match value {
         None => {
             return Err(Error::new(
@@ -165,9 +167,14 @@ pub mod tests {
             },
         };
 
-        let error = eval_capture(&capture, &variables, &http::xml_three_users_http_response())
-            .err()
-            .unwrap();
+        let error = eval_capture(
+            &capture,
+            &variables,
+            &http::xml_three_users_http_response(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 7 });
         assert_eq!(error.inner, RunnerError::QueryInvalidXpathEval);
     }
@@ -225,6 +232,7 @@ pub mod tests {
                 &user_count_capture(),
                 &variables,
                 &http::xml_three_users_http_response(),
+                false,
             )
             .unwrap(),
             CaptureResult {
@@ -234,7 +242,13 @@ pub mod tests {
         );
 
         assert_eq!(
-            eval_capture(&duration_capture(), &variables, &http::json_http_response()).unwrap(),
+            eval_capture(
+                &duration_capture(),
+                &variables,
+                &http::json_http_response(),
+                false
+            )
+            .unwrap(),
             CaptureResult {
                 name: "duration".to_string(),
                 value: Value::Number(Number::from(1.5)),
@@ -16,18 +16,20 @@
  *
  */
 use std::collections::HashMap;
+use std::thread;
 use std::time::Duration;
 
 use hurl_core::ast::*;
 
 use crate::http;
 use crate::http::ClientOptions;
-use crate::runner::error::Error;
+use crate::runner::error::{Error, RunnerError};
 use crate::runner::result::{AssertResult, EntryResult};
 use crate::runner::runner_options::RunnerOptions;
 use crate::runner::value::Value;
 use crate::runner::{request, response, CaptureResult};
 use crate::util::logger::{Logger, Verbosity};
+use crate::util::term::Stdout;
 
 /// Runs an `entry` with `http_client` and returns one [`EntryResult`].
 ///
@@ -35,18 +37,22 @@ use crate::util::logger::{Logger, Verbosity};
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+/// With the `no-buffer` option, the response body is written to `stdout` as it's received.
 pub fn run(
     entry: &Entry,
     entry_index: usize,
     http_client: &mut http::Client,
     variables: &mut HashMap<String, Value>,
     runner_options: &RunnerOptions,
+    stdout: &mut Stdout,
     logger: &mut Logger,
 ) -> EntryResult {
     let compressed = runner_options.compressed;
+    // With `raw`, asserts and captures on the response bytes see the body as received.
+    let raw = runner_options.raw;
     let source_info = entry.source_info();
     let context_dir = &runner_options.context_dir;
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
+    let mut http_request = match request::eval_request(&entry.request, variables, context_dir) {
         Ok(r) => r,
         Err(error) => {
             return EntryResult {
@@ -58,6 +64,7 @@ pub fn run(
             };
         }
     };
+    apply_request_options(&mut http_request, runner_options);
     let client_options = ClientOptions::from(runner_options, logger.verbosity);
 
     // Experimental features with cookie storage
@@ -81,22 +88,51 @@ pub fn run(
         logger,
     );
 
-    // Run the HTTP requests (optionally follow redirection)
-    let calls = match http_client.execute_with_redirect(&http_request, &client_options, logger) {
-        Ok(calls) => calls,
-        Err(http_error) => {
-            let start = entry.request.url.source_info.start;
-            let end = entry.request.url.source_info.end;
-            let error_source_info = SourceInfo::new(start, end);
-            let error = Error::new(error_source_info, http_error.into(), false);
-            return EntryResult {
-                entry_index,
-                source_info,
-                errors: vec![error],
-                compressed,
-                ..Default::default()
-            };
+    // Run the HTTP requests (optionally follow redirection), retrying on connection errors.
+    // With `no-buffer`, the response body can be written to standard output as it's received.
+    let mut stream_stdout = if runner_options.streams_output() {
+        Some(stdout)
+    } else {
+        None
+    };
+    let mut connect_retry_count = 0;
+    let calls = loop {
+        let http_error = match http_client.execute_with_redirect(
+            &http_request,
+            &client_options,
+            stream_stdout.as_deref_mut(),
+            logger,
+        ) {
+            Ok(calls) => break calls,
+            Err(http_error) => http_error,
+        };
+        let can_retry = match runner_options.connect_retries {
+            Retry::None => false,
+            Retry::Finite(n) => connect_retry_count < n,
+            Retry::Infinite => true,
+        };
+        if http_error.is_connection_error() && can_retry {
+            connect_retry_count += 1;
+            let delay = runner_options.retry_delay();
+            logger.debug("");
+            logger.debug_important(&format!(
+                "Retry connection (x{connect_retry_count} pause {} ms)",
+                delay.as_millis()
+            ));
+            thread::sleep(delay);
+            continue;
         }
+        let start = entry.request.url.source_info.start;
+        let end = entry.request.url.source_info.end;
+        let error_source_info = SourceInfo::new(start, end);
+        let error = Error::new(error_source_info, http_error.into(), false);
+        return EntryResult {
+            entry_index,
+            source_info,
+            errors: vec![error],
+            compressed,
+            ..Default::default()
+        };
     };
 
     // We runs capture and asserts on the last HTTP request/response chains.
@@ -140,7 +176,7 @@ pub fn run(
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, http_response, variables) {
+            match response::eval_captures(response_spec, http_response, variables, raw) {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -164,12 +200,22 @@ pub fn run(
     if !runner_options.ignore_asserts {
         if let Some(response_spec) = &entry.response {
             let mut other_asserts =
-                response::eval_asserts(response_spec, variables, http_response, context_dir);
+                response::eval_asserts(response_spec, variables, http_response, context_dir, raw);
             asserts.append(&mut other_asserts);
         }
     };
 
-    let errors = asserts_to_errors(&asserts);
+    let mut errors = asserts_to_errors(&asserts);
+    // With `fail-with-body`, the response body has been captured and asserted, but an HTTP error
+    // status still fails the entry.
+    if runner_options.fail_with_body && http_response.status >= 400 {
+        let error = Error::new(
+            entry.request.url.source_info,
+            RunnerError::FailWithBody(http_response.status),
+            false,
+        );
+        errors.push(error);
+    }
 
     EntryResult {
         entry_index,
@@ -199,30 +245,68 @@ fn asserts_to_errors(asserts: &[AssertResult]) -> Vec<Error> {
 impl ClientOptions {
     fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
         ClientOptions {
-            aws_sigv4: runner_options.aws_sigv4.clone(),
+            aws_sigv4: runner_options.aws_sigv4.as_ref().map(|aws_sigv4| {
+                aws_sigv4_with(
+                    aws_sigv4,
+                    runner_options.aws_region.as_deref(),
+                    runner_options.aws_service.as_deref(),
+                )
+            }),
             cacert_file: runner_options.cacert_file.clone(),
             client_cert_file: runner_options.client_cert_file.clone(),
+            client_cert_type: runner_options.client_cert_type,
             client_key_file: runner_options.client_key_file.clone(),
+            client_key_type: runner_options.client_key_type,
+            client_key_password: runner_options.client_key_password.clone(),
             compressed: runner_options.compressed,
             connect_timeout: runner_options.connect_timeout,
             connects_to: runner_options.connects_to.clone(),
+            content_length: runner_options.content_length,
             cookie_input_file: runner_options.cookie_input_file.clone(),
+            dns_cache_ttl: runner_options.dns_cache_ttl,
+            expect_100_timeout: runner_options.expect_100_timeout,
             follow_location: runner_options.follow_location,
             follow_location_trusted: runner_options.follow_location_trusted,
+            fresh_connect: runner_options.fresh_connect,
+            globoff: runner_options.globoff,
+            happy_eyeballs_timeout: runner_options.happy_eyeballs_timeout,
+            haproxy_protocol: runner_options.haproxy_protocol,
             http_version: runner_options.http_version,
             ip_resolve: runner_options.ip_resolve,
+            limit_rate: runner_options.limit_rate,
+            login_options: runner_options.login_options.clone(),
             max_redirect: runner_options.max_redirect,
+            max_header_size: runner_options.max_header_size,
+            max_filesize: runner_options.max_filesize,
             netrc: runner_options.netrc,
             netrc_file: runner_options.netrc_file.clone(),
             netrc_optional: runner_options.netrc_optional,
+            no_buffer: runner_options.no_buffer,
             path_as_is: runner_options.path_as_is,
+            post_redirect: post_redirect(runner_options),
             proxy: runner_options.proxy.clone(),
+            proxy_user: runner_options.proxy_user.clone(),
+            proxy_insecure: runner_options.proxy_insecure,
+            proxy_cacert_file: runner_options.proxy_cacert_file.clone(),
+            range: runner_options.range.clone(),
+            referer: runner_options.referer.clone(),
             no_proxy: runner_options.no_proxy.clone(),
             insecure: runner_options.insecure,
             resolves: runner_options.resolves.clone(),
             retry: runner_options.retry,
+            send_fragment: runner_options.send_fragment,
+            ssh_public_key_file: runner_options.ssh_public_key_file.clone(),
+            ssh_private_key_file: runner_options.ssh_private_key_file.clone(),
             ssl_no_revoke: runner_options.ssl_no_revoke,
+            speed_limit: runner_options.speed_limit,
+            speed_time: runner_options.speed_time,
+            tcp_keepalive: runner_options.tcp_keepalive,
+            tcp_keepidle: runner_options.tcp_keepidle,
+            tcp_keepintvl: runner_options.tcp_keepintvl,
+            tcp_nodelay: runner_options.tcp_nodelay,
             timeout: runner_options.timeout,
+            tls_max: runner_options.tls_max,
+            trace_body: runner_options.trace_body,
             unix_socket: runner_options.unix_socket.clone(),
             user: runner_options.user.clone(),
             user_agent: runner_options.user_agent.clone(),
@@ -235,6 +319,122 @@ impl ClientOptions {
     }
 }
 
+/// Returns the AWS SigV4 option `aws_sigv4` (`provider1[:provider2[:region[:service]]]`) with
+/// its region and service replaced by `region` and `service`, if any.
+fn aws_sigv4_with(aws_sigv4: &str, region: Option<&str>, service: Option<&str>) -> String {
+    if region.is_none() && service.is_none() {
+        return aws_sigv4.to_string();
+    }
+    let mut parts = aws_sigv4.splitn(4, ':').collect::<Vec<_>>();
+    // When omitted, the second provider defaults to the first one.
+    if parts.len() < 2 {
+        parts.push(parts[0]);
+    }
+    parts.resize(4, "");
+    if let Some(region) = region {
+        parts[2] = region;
+    }
+    if let Some(service) = service {
+        parts[3] = service;
+    }
+    parts.join(":").trim_end_matches(':').to_string()
+}
+
+/// Applies the request level `runner_options` (URL, query, method, body and headers) to `request`.
+fn apply_request_options(request: &mut http::RequestSpec, runner_options: &RunnerOptions) {
+    if let Some(url) = &runner_options.url {
+        request.url = url.clone();
+    }
+    // The query parameters are URL-encoded and appended to the URL by the HTTP client.
+    request
+        .querystring
+        .extend(runner_options.querystring.iter().cloned());
+    if let Some(method) = &runner_options.request_method {
+        request.method = http::Method(method.clone());
+    }
+    if let Some(body) = &runner_options.data_binary {
+        request.body = body.clone();
+    }
+    // The body is replaced but the request headers, including an explicit `Content-Type`, are kept.
+    if let Some(body) = &runner_options.body_file {
+        request.body = body.clone();
+    }
+    if !runner_options.form.is_empty() {
+        request
+            .multipart
+            .extend(runner_options.form.iter().cloned());
+        if request.implicit_content_type.is_none() {
+            request.implicit_content_type = Some("multipart/form-data".to_string());
+        }
+    }
+    // Like curl `--json`, JSON headers are only defaults: request headers and options override them.
+    if let Some(json) = &runner_options.json {
+        request.body = http::Body::Text(json.clone());
+        set_default_header(request, http::CONTENT_TYPE, "application/json");
+        set_default_header(request, http::ACCEPT, "application/json");
+    }
+    if runner_options.crlf {
+        request.body = crlf_body(&request.body);
+    }
+    if let Some(accept) = &runner_options.accept {
+        set_header(request, http::ACCEPT, accept);
+    }
+    if let Some(accept_encoding) = &runner_options.accept_encoding {
+        set_header(request, http::ACCEPT_ENCODING, accept_encoding);
+    }
+    if let Some(content_type) = &runner_options.content_type {
+        set_header(request, http::CONTENT_TYPE, content_type);
+    }
+}
+
+/// Returns a copy of `body` where each LF is converted to CRLF, like curl `--crlf`.
+fn crlf_body(body: &http::Body) -> http::Body {
+    let crlf = |bytes: &[u8]| {
+        let mut converted = Vec::with_capacity(bytes.len());
+        for &b in bytes {
+            if b == b'\n' {
+                converted.push(b'\r');
+            }
+            converted.push(b);
+        }
+        converted
+    };
+    match body {
+        http::Body::Text(text) => http::Body::Text(text.replace('\n', "\r\n")),
+        http::Body::Binary(bytes) => http::Body::Binary(crlf(bytes)),
+        http::Body::File(bytes, filename) => http::Body::File(crlf(bytes), filename.clone()),
+    }
+}
+
+/// Sets the header `name` of `request` to `value`, replacing any existing header of this name.
+fn set_header(request: &mut http::RequestSpec, name: &str, value: &str) {
+    request.headers.retain(|h| !h.name_eq(name));
+    request.headers.push(http::Header::new(name, value));
+}
+
+/// Sets the header `name` of `request` to `value`, if `request` has no header of this name.
+fn set_default_header(request: &mut http::RequestSpec, name: &str, value: &str) {
+    if !request.headers.contains_key(name) {
+        request.headers.push(http::Header::new(name, value));
+    }
+}
+
+/// Returns the POST redirections bitmask of `runner_options`, with the same bits as
+/// [`CURLOPT_POSTREDIR`](https://curl.se/libcurl/c/CURLOPT_POSTREDIR.html).
+fn post_redirect(runner_options: &RunnerOptions) -> u32 {
+    let mut post_redirect = 0;
+    if runner_options.post301 {
+        post_redirect |= http::POST_REDIRECT_301;
+    }
+    if runner_options.post302 {
+        post_redirect |= http::POST_REDIRECT_302;
+    }
+    if runner_options.post303 {
+        post_redirect |= http::POST_REDIRECT_303;
+    }
+    post_redirect
+}
+
 /// Logs this HTTP `request`.
 fn log_request(
     http_client: &mut http::Client,
@@ -252,6 +452,9 @@ fn log_request(
     logger.debug("");
     logger.debug_important("Request:");
     logger.debug(&format!("{} {}", request.method, request.url));
+    if client_options.path_as_is {
+        logger.debug("Path as is: dot segments of the URL path are not removed");
+    }
     for header in &request.headers {
         logger.debug(&header.to_string());
     }
@@ -283,12 +486,33 @@ fn log_request(
     logger.debug("Request can be run with the following curl command:");
     let context_dir = &runner_options.context_dir;
     let output = &runner_options.output;
+    let client_options = redact_secrets(client_options);
     let curl_command =
-        http_client.curl_command_line(request, context_dir, output.as_ref(), client_options);
+        http_client.curl_command_line(request, context_dir, output.as_ref(), &client_options);
     logger.debug(&curl_command);
     logger.debug("");
 }
 
+/// Returns a copy of the client `options` where secrets (user and proxy credentials, key
+/// passphrase) are masked, so they don't leak in the logged curl command.
+fn redact_secrets(options: &ClientOptions) -> ClientOptions {
+    let mut options = options.clone();
+    options.proxy_user = options.proxy_user.as_deref().map(redact_credentials);
+    options.user = options.user.as_deref().map(redact_credentials);
+    if options.client_key_password.is_some() {
+        options.client_key_password = Some("***".to_string());
+    }
+    options
+}
+
+/// Masks the password of `credentials` given as `username:password`.
+fn redact_credentials(credentials: &str) -> String {
+    let username = credentials
+        .split_once(':')
+        .map_or(credentials, |(username, _)| username);
+    format!("{username}:***")
+}
+
 /// Logs the `captures` from the entry HTTP response.
 fn log_captures(captures: &[CaptureResult], logger: &mut Logger) {
     if captures.is_empty() {
@@ -299,3 +523,179 @@ fn log_captures(captures: &[CaptureResult], logger: &mut Logger) {
         logger.capture(&c.name, &c.value);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::RunnerOptionsBuilder;
+
+    #[test]
+    fn test_aws_sigv4_with() {
+        assert_eq!(
+            aws_sigv4_with("aws:amz:eu-central-1:sts", None, None),
+            "aws:amz:eu-central-1:sts"
+        );
+        assert_eq!(
+            aws_sigv4_with("aws:amz:eu-central-1:sts", Some("us-east-1"), None),
+            "aws:amz:us-east-1:sts"
+        );
+        assert_eq!(
+            aws_sigv4_with("aws:amz", Some("us-east-1"), Some("s3")),
+            "aws:amz:us-east-1:s3"
+        );
+        assert_eq!(
+            aws_sigv4_with("aws", Some("us-east-1"), None),
+            "aws:aws:us-east-1"
+        );
+    }
+
+    #[test]
+    fn test_post_redirect() {
+        let runner_options = RunnerOptionsBuilder::new().build();
+        assert_eq!(post_redirect(&runner_options), 0);
+
+        let runner_options = RunnerOptionsBuilder::new().post302(true).build();
+        assert_eq!(post_redirect(&runner_options), 0b010);
+
+        let runner_options = RunnerOptionsBuilder::new()
+            .post301(true)
+            .post303(true)
+            .build();
+        assert_eq!(post_redirect(&runner_options), 0b101);
+
+        let runner_options = RunnerOptionsBuilder::new()
+            .post301(true)
+            .post302(true)
+            .post303(true)
+            .build();
+        assert_eq!(post_redirect(&runner_options), 0b111);
+    }
+
+    #[test]
+    fn test_apply_request_options_json() {
+        let mut runner_options = RunnerOptionsBuilder::new().build();
+        runner_options.json = Some(r#"{"name":"Bob"}"#.to_string());
+
+        let mut request = http::RequestSpec::default();
+        apply_request_options(&mut request, &runner_options);
+        assert_eq!(
+            request.body,
+            http::Body::Text(r#"{"name":"Bob"}"#.to_string())
+        );
+        assert_eq!(
+            request.headers.values(http::CONTENT_TYPE),
+            vec!["application/json"]
+        );
+        assert_eq!(
+            request.headers.values(http::ACCEPT),
+            vec!["application/json"]
+        );
+
+        // Explicit request headers are kept.
+        let mut request = http::RequestSpec::default();
+        request.headers.push(http::Header::new(
+            "content-type",
+            "application/vnd.api+json",
+        ));
+        apply_request_options(&mut request, &runner_options);
+        assert_eq!(
+            request.headers.values(http::CONTENT_TYPE),
+            vec!["application/vnd.api+json"]
+        );
+        assert_eq!(
+            request.headers.values(http::ACCEPT),
+            vec!["application/json"]
+        );
+    }
+
+    #[test]
+    fn test_apply_request_options_url() {
+        let mut request = http::RequestSpec {
+            url: "https://example.org/v1/items".to_string(),
+            ..Default::default()
+        };
+        let runner_options = RunnerOptionsBuilder::new().build();
+        apply_request_options(&mut request, &runner_options);
+        assert_eq!(request.url, "https://example.org/v1/items");
+
+        // The option URL takes precedence over the entry URL.
+        let mut runner_options = RunnerOptionsBuilder::new().build();
+        runner_options.url = Some("https://example.org/v2/items".to_string());
+        apply_request_options(&mut request, &runner_options);
+        assert_eq!(request.url, "https://example.org/v2/items");
+    }
+
+    #[test]
+    fn test_apply_request_options_crlf() {
+        let mut runner_options = RunnerOptionsBuilder::new().build();
+        runner_options.crlf = true;
+
+        let mut request = http::RequestSpec {
+            body: http::Body::Text("HELO\nQUIT\n".to_string()),
+            ..Default::default()
+        };
+        apply_request_options(&mut request, &runner_options);
+        assert_eq!(request.body.bytes(), b"HELO\r\nQUIT\r\n");
+
+        let mut request = http::RequestSpec {
+            body: http::Body::File(b"a\nb".to_vec(), "data.txt".to_string()),
+            ..Default::default()
+        };
+        apply_request_options(&mut request, &runner_options);
+        assert_eq!(
+            request.body,
+            http::Body::File(b"a\r\nb".to_vec(), "data.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_client_options_aws_sigv4() {
+        let runner_options = RunnerOptionsBuilder::new()
+            .aws_sigv4(Some("aws:amz:eu-central-1:sts".to_string()))
+            .aws_region(Some("us-east-1".to_string()))
+            .aws_service(Some("s3".to_string()))
+            .build();
+        let client_options = ClientOptions::from(&runner_options, None);
+        assert_eq!(
+            client_options.aws_sigv4,
+            Some("aws:amz:us-east-1:s3".to_string())
+        );
+
+        let runner_options = RunnerOptionsBuilder::new()
+            .aws_region(Some("us-east-1".to_string()))
+            .build();
+        let client_options = ClientOptions::from(&runner_options, None);
+        assert_eq!(client_options.aws_sigv4, None);
+    }
+
+    #[test]
+    fn test_logged_curl_command_redacts_secrets() {
+        let mut http_client = http::Client::new();
+        let request = http::RequestSpec {
+            method: http::Method("GET".to_string()),
+            url: "http://localhost:8000/hello".to_string(),
+            ..Default::default()
+        };
+        let runner_options = RunnerOptionsBuilder::new()
+            .client_key_password(Some("passphrase".to_string()))
+            .proxy_user(Some("alice:password".to_string()))
+            .user(Some("bob:secret".to_string()))
+            .build();
+        let client_options = ClientOptions::from(&runner_options, None);
+        let client_options = redact_secrets(&client_options);
+
+        let cmd = http_client.curl_command_line(
+            &request,
+            &runner_options.context_dir,
+            None,
+            &client_options,
+        );
+        assert_eq!(
+            cmd,
+            "curl --pass '***' --proxy-user 'alice:***' --user 'bob:***' 'http://localhost:8000/hello'"
+        );
+        assert!(!cmd.contains("secret"));
+        assert!(!cmd.contains("passphrase"));
+        assert!(!cmd.contains("password"));
+    }
+}
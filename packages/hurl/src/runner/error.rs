@@ -62,8 +62,17 @@ pub enum RunnerError {
     AssertVersion {
         actual: String,
     },
+    /// The option `name` conflicts with a previous option `other`.
+    ConflictingOption {
+        name: String,
+        other: String,
+    },
     CouldNotParseResponse,
     CouldNotUncompressResponse(String),
+    /// The environment variable `name` of a variable value is not set.
+    EnvVariableNotDefined {
+        name: String,
+    },
     /// I/O read error on `path`.
     FileReadAccess {
         path: PathBuf,
@@ -73,21 +82,39 @@ pub enum RunnerError {
         path: PathBuf,
         error: String,
     },
+    /// The HTTP response has an error `status` (>= 400) with the `fail-with-body` option.
+    FailWithBody(u32),
     FilterDecode(String),
     FilterInvalidEncoding(String),
     FilterInvalidInput(String),
     FilterMissingInput,
     HttpConnection(String),
+    /// AWS SigV4 option `value` has an empty provider.
+    InvalidAwsSigV4(String),
     InvalidJson {
         value: String,
     },
+    /// Form option `value` is not a `field=value` or `field=@file` pair.
+    InvalidFormOption {
+        value: String,
+    },
+    /// Query option `value` is not a `name=value` pair.
+    InvalidQueryOption {
+        value: String,
+    },
     InvalidCharset {
         charset: String,
     },
     InvalidDecoding {
         charset: String,
     },
+    /// Range option `value` is not a valid byte range.
+    InvalidRange(String),
     InvalidRegex,
+    /// Retry jitter option `value` is not a percentage between 0 and 100.
+    InvalidRetryJitter(u64),
+    /// Speed limit option `value` doesn't fit in the 32 bits expected by libcurl.
+    InvalidSpeedLimit(u64),
     InvalidUrl(String),
     InvalidUrlPrefix(String),
     NoQueryResult,
@@ -109,6 +136,8 @@ pub enum RunnerError {
     TooManyRedirect,
     UnsupportedContentEncoding(String),
     UnsupportedHttpVersion(RequestedHttpVersion),
+    /// A `~user` path, only the home directory of the current user (`~/`) is supported.
+    UnsupportedHomeDirectory(String),
     UnrenderableVariable {
         name: String,
         value: String,
@@ -132,19 +161,30 @@ impl hurl_core::error::Error for Error {
             RunnerError::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerError::AssertStatus { .. } => "Assert status code".to_string(),
             RunnerError::AssertVersion { .. } => "Assert HTTP version".to_string(),
+            RunnerError::ConflictingOption { .. } => "Conflicting options".to_string(),
             RunnerError::CouldNotParseResponse => "HTTP connection".to_string(),
             RunnerError::CouldNotUncompressResponse(..) => "Decompression error".to_string(),
+            RunnerError::EnvVariableNotDefined { .. } => {
+                "Undefined environment variable".to_string()
+            }
             RunnerError::FileReadAccess { .. } => "File read access".to_string(),
             RunnerError::FileWriteAccess { .. } => "File write access".to_string(),
+            RunnerError::FailWithBody(_) => "HTTP error status".to_string(),
             RunnerError::FilterDecode { .. } => "Filter error".to_string(),
             RunnerError::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerError::FilterInvalidInput { .. } => "Filter error".to_string(),
             RunnerError::FilterMissingInput => "Filter error".to_string(),
             RunnerError::HttpConnection { .. } => "HTTP connection".to_string(),
+            RunnerError::InvalidAwsSigV4(..) => "Invalid AWS SigV4 option".to_string(),
             RunnerError::InvalidCharset { .. } => "Invalid charset".to_string(),
             RunnerError::InvalidDecoding { .. } => "Invalid decoding".to_string(),
+            RunnerError::InvalidFormOption { .. } => "Invalid form option".to_string(),
             RunnerError::InvalidJson { .. } => "Invalid JSON".to_string(),
+            RunnerError::InvalidQueryOption { .. } => "Invalid query option".to_string(),
+            RunnerError::InvalidRange(..) => "Invalid range".to_string(),
             RunnerError::InvalidRegex => "Invalid regex".to_string(),
+            RunnerError::InvalidRetryJitter(..) => "Invalid retry jitter option".to_string(),
+            RunnerError::InvalidSpeedLimit(..) => "Invalid speed limit option".to_string(),
             RunnerError::InvalidUrl(..) => "Invalid URL".to_string(),
             RunnerError::InvalidUrlPrefix(..) => "Invalid URL".to_string(),
             RunnerError::NoQueryResult => "No query result".to_string(),
@@ -160,6 +200,7 @@ impl hurl_core::error::Error for Error {
             RunnerError::UnrenderableVariable { .. } => "Unrenderable variable".to_string(),
             RunnerError::UnsupportedContentEncoding(..) => "Decompression error".to_string(),
             RunnerError::UnsupportedHttpVersion(..) => "Unsupported HTTP version".to_string(),
+            RunnerError::UnsupportedHomeDirectory(..) => "Unsupported home directory".to_string(),
         }
     }
 
@@ -186,16 +227,25 @@ impl hurl_core::error::Error for Error {
             }
             RunnerError::AssertStatus { actual, .. } => format!("actual value is <{actual}>"),
             RunnerError::AssertVersion { actual, .. } => format!("actual value is <{actual}>"),
+            RunnerError::ConflictingOption { name, other } => {
+                format!("the option <{name}> conflicts with the option <{other}>")
+            }
             RunnerError::CouldNotParseResponse => "could not parse response".to_string(),
             RunnerError::CouldNotUncompressResponse(algorithm) => {
                 format!("could not uncompress response with {algorithm}")
             }
+            RunnerError::EnvVariableNotDefined { name } => {
+                format!("you must set the environment variable {name}")
+            }
             RunnerError::FileReadAccess { path } => {
                 format!("file {} can not be read", path.to_string_lossy())
             }
             RunnerError::FileWriteAccess { path, error } => {
                 format!("{} can not be written ({error})", path.to_string_lossy())
             }
+            RunnerError::FailWithBody(status) => {
+                format!("HTTP status <{status}> is an error (fail-with-body option)")
+            }
             RunnerError::FilterDecode(encoding) => {
                 format!("value can not be decoded with <{encoding}> encoding")
             }
@@ -207,16 +257,39 @@ impl hurl_core::error::Error for Error {
             }
             RunnerError::FilterMissingInput => "missing value to apply filter".to_string(),
             RunnerError::HttpConnection(message) => message.to_string(),
+            RunnerError::InvalidAwsSigV4(value) => {
+                format!("expecting a non-empty provider in <{value}>, e.g. aws:amz:region:service")
+            }
             RunnerError::InvalidCharset { charset } => {
                 format!("the charset '{charset}' is not valid")
             }
             RunnerError::InvalidDecoding { charset } => {
                 format!("the body can not be decoded with charset '{charset}'")
             }
+            RunnerError::InvalidFormOption { value } => {
+                format!(
+                    "expecting a 'field=value' or 'field=@file' form option, actual is <{value}>"
+                )
+            }
+            RunnerError::InvalidQueryOption { value } => {
+                format!("expecting a 'name=value' query option, actual is <{value}>")
+            }
             RunnerError::InvalidJson { value } => {
                 format!("actual value is <{value}>")
             }
+            RunnerError::InvalidRange(range) => {
+                format!("invalid byte range <{range}>, expecting a list of N-M, N- or -N ranges")
+            }
             RunnerError::InvalidRegex => "regex expression is not valid".to_string(),
+            RunnerError::InvalidRetryJitter(value) => {
+                format!("retry jitter <{value}> must be a percentage between 0 and 100")
+            }
+            RunnerError::InvalidSpeedLimit(value) => {
+                format!(
+                    "speed limit <{value}> is too large, maximum is {}",
+                    u32::MAX
+                )
+            }
             RunnerError::InvalidUrl(url) => format!("invalid URL <{url}>"),
             RunnerError::InvalidUrlPrefix(url) => {
                 format!("URL <{url}> must start with http:// or https://")
@@ -255,6 +328,11 @@ impl hurl_core::error::Error for Error {
             RunnerError::UnsupportedHttpVersion(version) => {
                 format!("{version} is not supported, check --version")
             }
+            RunnerError::UnsupportedHomeDirectory(path) => {
+                format!(
+                    "path <{path}> can only start with the home directory of the current user ~/"
+                )
+            }
         }
     }
 }
@@ -280,6 +358,12 @@ impl From<HttpError> for RunnerError {
             } => RunnerError::HttpConnection(format!(
                 "Option {option} requires libcurl version {minimum_version} or higher"
             )),
+            HttpError::ResponseBodyTooLarge { max_filesize } => RunnerError::HttpConnection(
+                format!("response body exceeds the maximum size of {max_filesize} bytes"),
+            ),
+            HttpError::ResponseHeadersTooLarge { max_header_size } => RunnerError::HttpConnection(
+                format!("response headers exceed the maximum size of {max_header_size} bytes"),
+            ),
             HttpError::StatuslineIsMissing => {
                 RunnerError::HttpConnection("status line is missing".to_string())
             }
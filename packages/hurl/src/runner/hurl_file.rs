@@ -17,11 +17,11 @@
  */
 use std::collections::HashMap;
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 use hurl_core::ast::VersionValue::VersionAnyLegacy;
-use hurl_core::ast::{Body, Bytes, Entry, MultilineString, Request, Response, Retry};
+use hurl_core::ast::{Body, Bytes, Entry, MultilineString, Request, Response, Retry, RetryTrigger};
 use hurl_core::error::Error;
 use hurl_core::parser;
 
@@ -29,7 +29,7 @@ use crate::http::{Call, Client};
 use crate::runner::event::EventListener;
 use crate::runner::progress::{Mode, SeqProgress};
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{entry, options, EntryResult, HurlResult, Value};
+use crate::runner::{entry, options, EntryResult, HurlResult, RunnerError, Value};
 use crate::util::logger::{ErrorFormat, Logger, LoggerOptions};
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
@@ -155,10 +155,13 @@ pub fn run_entries(
 ) -> HurlResult {
     let mut http_client = Client::new();
     let mut entries_result = vec![];
+    let mut skipped_entries = 0;
     let mut variables = variables.clone();
     let mut entry_index = runner_options.from_entry.unwrap_or(1);
     let n = runner_options.to_entry.unwrap_or(entries.len());
     let mut retry_count = 1;
+    let mut retry_start = Instant::now();
+    let mut repeat_count = 1;
     let default_verbosity = logger.verbosity;
     let start = Instant::now();
     let timestamp = Utc::now().timestamp();
@@ -196,11 +199,19 @@ pub fn run_entries(
         );
         logger.debug_important(&format!("Executing entry {entry_index}"));
 
+        // All the attempts of an entry share the same retry deadline.
+        if retry_count == 1 {
+            retry_start = Instant::now();
+        }
+
         warn_deprecated(entry, logger);
+        warn_shadowed_connect_to(entry, logger);
 
         listener.on_running(entry_index - 1, n, &mut logger.stderr);
 
         // The real execution of the entry happens here, with the overridden entry options.
+        // Variables defined by `variable-local` options are only visible to this entry.
+        let local_variables = options::get_local_variables(entry, &variables);
         let options = options::get_entry_options(entry, runner_options, &mut variables, logger);
         let entry_result = match &options {
             Err(error) => EntryResult {
@@ -210,9 +221,11 @@ pub fn run_entries(
                 ..Default::default()
             },
             Ok(options) => {
-                if options.skip {
+                if options.skip || options.repeat == 0 {
                     logger.debug("");
                     logger.debug_important(&format!("Entry {entry_index} has been skipped"));
+                    options::restore_local_variables(local_variables, &mut variables);
+                    skipped_entries += 1;
                     entry_index += 1;
                     continue;
                 }
@@ -233,46 +246,77 @@ pub fn run_entries(
                     &mut http_client,
                     &mut variables,
                     options,
+                    stdout,
                     logger,
                 )
             }
         };
+        options::restore_local_variables(local_variables, &mut variables);
 
         // Check if we need to retry.
         let has_error = !entry_result.errors.is_empty();
-        let (retry_opts, retry_interval) = match &options {
-            Ok(options) => (options.retry, options.retry_interval),
-            Err(_) => (runner_options.retry, runner_options.retry_interval),
+        let (retry_opts, retry_delay, retry_deadline, retry_on, retry_on_status) = match &options {
+            Ok(options) => (
+                options.retry,
+                options.retry_delay(),
+                options.retry_deadline,
+                options.retry_on,
+                &options.retry_on_status,
+            ),
+            Err(_) => (
+                runner_options.retry,
+                runner_options.retry_delay(),
+                runner_options.retry_deadline,
+                runner_options.retry_on,
+                &runner_options.retry_on_status,
+            ),
         };
         // The retry threshold can only reached with a finite positive number of retries
-        let retry_max_reached = if let Retry::Finite(r) = retry_opts {
+        let retry_count_reached = if let Retry::Finite(r) = retry_opts {
             retry_count > r
         } else {
             false
         };
+        let retry_deadline_reached = !matches!(retry_opts, Retry::None)
+            && is_retry_deadline_reached(retry_start.elapsed(), retry_delay, retry_deadline);
+        let retry_max_reached = retry_count_reached || retry_deadline_reached;
         // If `retry_max_reached` is true, we print now a warning, before displaying any assert
         // error so any potential error is the last thing displayed to the user.
         // If `retry_max_reached` is not true (for instance `retry`is true, or there is no error
         // we first log the error and a potential warning about retrying.
-        if retry_max_reached {
+        if retry_count_reached {
             logger.debug_important("Retry max count reached, no more retry");
             logger.debug("");
+        } else if retry_deadline_reached && has_error {
+            logger.debug_important("Retry deadline reached, no more retry");
+            logger.debug("");
         }
 
+        // When a list of status codes is given, we only retry failed responses with one of these
+        // codes, otherwise errors of the `retry-on` kind trigger a retry.
+        let retryable = if retry_on_status.is_empty() {
+            is_retry_triggered(&entry_result, retry_on)
+        } else {
+            is_retry_status(&entry_result, retry_on_status)
+        };
+
         // We logs eventual errors, only if we're not retrying the current entry...
-        let retry = !matches!(retry_opts, Retry::None) && !retry_max_reached && has_error;
+        let retry = !matches!(retry_opts, Retry::None) && !retry_max_reached && retryable;
         if has_error {
             log_errors(&entry_result, content, retry, logger);
         }
 
         // When --output is overridden on a request level, we output the HTTP response only if the
-        // call has succeeded.
-        if let Ok(RunnerOptions {
-            output: Some(output),
-            ..
-        }) = options
+        // call has succeeded (with `no-buffer`, a response to standard output has already been
+        // written as it was received).
+        if let Ok(
+            entry_options @ RunnerOptions {
+                output: Some(output),
+                ..
+            },
+        ) = &options
         {
-            if !has_error {
+            if !has_error && !entry_options.streams_output() {
                 // TODO: make output write and access error as part of entry result errors.
                 // For the moment, we deal the --output request failure as a simple warning and not
                 // an error. If we want to treat it as an error, we've to add it to the current
@@ -280,7 +324,7 @@ pub fn run_entries(
                 // specified path.
                 let source_info = entry.source_info();
                 if let Err(error) = entry_result.write_response(
-                    &output,
+                    output,
                     &runner_options.context_dir,
                     stdout,
                     source_info,
@@ -292,7 +336,7 @@ pub fn run_entries(
         entries_result.push(entry_result);
 
         if retry {
-            let delay = retry_interval.as_millis();
+            let delay = retry_delay.as_millis();
             logger.debug("");
             logger.debug_important(&format!(
                 "Retry entry {entry_index} (x{retry_count} pause {delay} ms)"
@@ -301,7 +345,7 @@ pub fn run_entries(
             // If we retry the entry, we do not want to display a 'blank' progress bar during the
             // sleep delay. During the pause, we artificially show the previously erased progress
             // line.
-            thread::sleep(retry_interval);
+            thread::sleep(retry_delay);
             continue;
         }
 
@@ -311,13 +355,30 @@ pub fn run_entries(
                 break;
             }
         }
-        if !runner_options.continue_on_error && has_error {
+        if has_error && stops_on_error(options.as_ref().ok(), runner_options) {
             break;
         }
 
+        // Repeat the entry if asked, as long as the current run has succeeded. A failed run
+        // stops the repetition so that it's the last result reported for this entry.
+        let repeat = match &options {
+            Ok(options) => options.repeat,
+            Err(_) => runner_options.repeat,
+        };
+        if !has_error && repeat_count < repeat {
+            repeat_count += 1;
+            retry_count = 1;
+            logger.debug("");
+            logger.debug_important(&format!(
+                "Repeat entry {entry_index} (x{repeat_count}/{repeat})"
+            ));
+            continue;
+        }
+
         // We pass to the next entry
         entry_index += 1;
         retry_count = 1;
+        repeat_count = 1;
     }
 
     let time_in_ms = start.elapsed().as_millis();
@@ -325,6 +386,7 @@ pub fn run_entries(
     let success = is_success(&entries_result);
     HurlResult {
         entries: entries_result,
+        skipped_entries,
         time_in_ms,
         success,
         cookies,
@@ -332,6 +394,19 @@ pub fn run_entries(
     }
 }
 
+/// Returns `true` if the run must stop after an entry with `entry_options` has failed.
+///
+/// An entry with `stop-on-failure` or `abort-on-error` stops the run, even with `--continue-on-error`.
+/// An entry with `continue-on-error` doesn't stop the run, even without `--continue-on-error`.
+fn stops_on_error(entry_options: Option<&RunnerOptions>, runner_options: &RunnerOptions) -> bool {
+    match entry_options {
+        Some(options) => {
+            options.stop_on_failure || options.abort_on_error || !options.continue_on_error
+        }
+        None => !runner_options.continue_on_error,
+    }
+}
+
 /// Returns `true` if all the entries results are successful, `false` otherwise.
 ///
 /// For a given list of entry results, only the last one on the same index is checked.
@@ -415,6 +490,18 @@ fn warn_deprecated(entry: &Entry, logger: &mut Logger) {
     }
 }
 
+/// Logs `connect-to` options overridden by a later `connect-to` option of the same entry.
+fn warn_shadowed_connect_to(entry: &Entry, logger: &mut Logger) {
+    let options = entry.request.options();
+    for error in parser::validate_connect_to(&options) {
+        let filename = &logger.filename;
+        let line = error.pos.line;
+        let column = error.pos.column;
+        let message = error.fixme();
+        logger.warning(&format!("{filename}:{line}:{column} {message}"));
+    }
+}
+
 // Returns the list of options that have non-default values.
 fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String)> {
     let default_options = RunnerOptions::default();
@@ -494,6 +581,43 @@ fn log_run_info(
     }
 }
 
+/// Returns `true` if `entry_result` has an error of the kind triggering a retry.
+///
+/// Transport errors are the errors of the HTTP exchange (connection failure, timeout...), when
+/// assert failures are errors on the response.
+fn is_retry_triggered(entry_result: &EntryResult, retry_on: RetryTrigger) -> bool {
+    entry_result.errors.iter().any(|error| match retry_on {
+        RetryTrigger::Asserts => error.assert,
+        RetryTrigger::Transport => matches!(error.inner, RunnerError::HttpConnection(_)),
+        RetryTrigger::All => true,
+    })
+}
+
+/// Returns `true` if `entry_result` has failed with a response whose status is one of the
+/// `retry_on_status` codes. A successful entry is never retried, whatever its status.
+fn is_retry_status(entry_result: &EntryResult, retry_on_status: &[u32]) -> bool {
+    if entry_result.errors.is_empty() {
+        return false;
+    }
+    match entry_result.calls.last() {
+        Some(call) => retry_on_status.contains(&call.response.status),
+        None => false,
+    }
+}
+
+/// Returns `true` if a retry, made after a pause of `delay`, would exceed the `deadline` of the
+/// entry attempts, `elapsed` being the time spent since the first attempt.
+fn is_retry_deadline_reached(
+    elapsed: Duration,
+    delay: Duration,
+    deadline: Option<Duration>,
+) -> bool {
+    match deadline {
+        Some(deadline) => elapsed + delay > deadline,
+        None => false,
+    }
+}
+
 /// Logs runner `errors`.
 /// If we're going to `retry` the entry, we log errors only in verbose. Otherwise, we log error on stderr.
 fn log_errors(entry_result: &EntryResult, content: &str, retry: bool, logger: &mut Logger) {
@@ -518,9 +642,144 @@ fn log_errors(entry_result: &EntryResult, content: &str, retry: bool, logger: &m
 
 #[cfg(test)]
 mod test {
+    use hurl_core::ast::{Pos, SourceInfo};
+
     use super::*;
+    use crate::http::{HeaderVec, Request, Response, Timings};
     use crate::runner::RunnerOptionsBuilder;
 
+    #[test]
+    fn abort_on_error_stops_run_with_continue_on_error() {
+        let runner_options = RunnerOptionsBuilder::new().continue_on_error(true).build();
+        assert!(!stops_on_error(None, &runner_options));
+
+        let entry_options = runner_options.clone();
+        assert!(!stops_on_error(Some(&entry_options), &runner_options));
+
+        let mut entry_options = runner_options.clone();
+        entry_options.abort_on_error = true;
+        assert!(stops_on_error(Some(&entry_options), &runner_options));
+
+        let runner_options = RunnerOptions::default();
+        assert!(stops_on_error(None, &runner_options));
+    }
+
+    #[test]
+    fn continue_on_error_overrides_fail_fast() {
+        let runner_options = RunnerOptions::default();
+        assert!(stops_on_error(Some(&runner_options), &runner_options));
+
+        let mut entry_options = runner_options.clone();
+        entry_options.continue_on_error = true;
+        assert!(!stops_on_error(Some(&entry_options), &runner_options));
+
+        // `abort-on-error` takes precedence on `continue-on-error`.
+        entry_options.abort_on_error = true;
+        assert!(stops_on_error(Some(&entry_options), &runner_options));
+    }
+
+    #[test]
+    fn retry_is_triggered_by_errors_of_retry_on_kind() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let transport = crate::runner::Error::new(
+            source_info,
+            RunnerError::HttpConnection("(7) Failed to connect".to_string()),
+            false,
+        );
+        let assert = crate::runner::Error::new(
+            source_info,
+            RunnerError::AssertStatus {
+                actual: "500".to_string(),
+            },
+            true,
+        );
+        let result = |errors| EntryResult {
+            errors,
+            ..EntryResult::default()
+        };
+
+        let transport_failure = result(vec![transport]);
+        assert!(is_retry_triggered(&transport_failure, RetryTrigger::All));
+        assert!(is_retry_triggered(
+            &transport_failure,
+            RetryTrigger::Transport
+        ));
+        assert!(!is_retry_triggered(
+            &transport_failure,
+            RetryTrigger::Asserts
+        ));
+
+        let assert_failure = result(vec![assert]);
+        assert!(is_retry_triggered(&assert_failure, RetryTrigger::All));
+        assert!(!is_retry_triggered(
+            &assert_failure,
+            RetryTrigger::Transport
+        ));
+        assert!(is_retry_triggered(&assert_failure, RetryTrigger::Asserts));
+
+        let success = result(vec![]);
+        assert!(!is_retry_triggered(&success, RetryTrigger::All));
+    }
+
+    #[test]
+    fn retry_is_triggered_by_failed_responses_with_status() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let assert = crate::runner::Error::new(
+            source_info,
+            RunnerError::AssertStatus {
+                actual: "503".to_string(),
+            },
+            true,
+        );
+        let result = |status, errors| EntryResult {
+            calls: vec![Call {
+                request: Request::new("GET", "http://localhost:8000", HeaderVec::new(), vec![]),
+                response: Response {
+                    status,
+                    ..Response::default()
+                },
+                timings: Timings::default(),
+            }],
+            errors,
+            ..EntryResult::default()
+        };
+        let retry_on_status = [429, 503];
+
+        assert!(is_retry_status(
+            &result(429, vec![assert.clone()]),
+            &retry_on_status
+        ));
+        assert!(is_retry_status(
+            &result(503, vec![assert.clone()]),
+            &retry_on_status
+        ));
+        assert!(!is_retry_status(
+            &result(500, vec![assert]),
+            &retry_on_status
+        ));
+        // A passing entry is not retried, even with a status in the list.
+        assert!(!is_retry_status(&result(503, vec![]), &retry_on_status));
+    }
+
+    #[test]
+    fn retry_stops_at_deadline() {
+        let ms = Duration::from_millis;
+        assert!(!is_retry_deadline_reached(ms(5000), ms(1000), None));
+        assert!(!is_retry_deadline_reached(ms(0), ms(1000), Some(ms(3000))));
+        assert!(!is_retry_deadline_reached(
+            ms(2000),
+            ms(1000),
+            Some(ms(3000))
+        ));
+        assert!(is_retry_deadline_reached(
+            ms(2500),
+            ms(1000),
+            Some(ms(3000))
+        ));
+        assert!(is_retry_deadline_reached(ms(3500), ms(0), Some(ms(3000))));
+        assert!(is_retry_deadline_reached(ms(1), ms(0), Some(ms(0))));
+    }
+
     #[test]
     fn get_non_default_options_returns_empty_when_default() {
         let options = RunnerOptions::default();
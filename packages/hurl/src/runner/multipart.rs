@@ -23,7 +23,7 @@ use hurl_core::ast::*;
 
 use crate::http;
 use crate::runner::body::eval_file;
-use crate::runner::error::Error;
+use crate::runner::error::{Error, RunnerError};
 use crate::runner::template::eval_template;
 use crate::runner::value::Value;
 use crate::util::path::ContextDir;
@@ -63,6 +63,46 @@ pub fn eval_file_param(
     })
 }
 
+/// Evaluates a `form` entry option to a multipart param.
+///
+/// The option value is either `field=value` for a text field, or `field=@file` for a file upload.
+pub fn eval_form_option(
+    form: &Template,
+    variables: &HashMap<String, Value>,
+    context_dir: &ContextDir,
+) -> Result<http::MultipartParam, Error> {
+    let value = eval_template(form, variables)?;
+    let Some((name, value)) = value.split_once('=') else {
+        let inner = RunnerError::InvalidFormOption { value };
+        return Err(Error::new(form.source_info, inner, false));
+    };
+    let name = name.to_string();
+    match value.strip_prefix('@') {
+        Some(filename) => {
+            let template = Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: filename.to_string(),
+                    encoded: filename.to_string(),
+                }],
+                source_info: form.source_info,
+            };
+            let data = eval_file(&template, variables, context_dir)?;
+            let content_type = filename_content_type(filename);
+            Ok(http::MultipartParam::FileParam(http::FileParam {
+                name,
+                filename: filename.to_string(),
+                data,
+                content_type,
+            }))
+        }
+        None => {
+            let value = value.to_string();
+            Ok(http::MultipartParam::Param(http::Param { name, value }))
+        }
+    }
+}
+
 pub fn file_value_content_type(
     file_value: &FileValue,
     variables: &HashMap<String, Value>,
@@ -70,28 +110,30 @@ pub fn file_value_content_type(
     let value = match file_value.content_type.clone() {
         None => {
             let value = eval_template(&file_value.filename, variables)?;
-            match Path::new(value.as_str())
-                .extension()
-                .and_then(OsStr::to_str)
-            {
-                Some("gif") => "image/gif".to_string(),
-                Some("jpg") => "image/jpeg".to_string(),
-                Some("jpeg") => "image/jpeg".to_string(),
-                Some("png") => "image/png".to_string(),
-                Some("svg") => "image/svg+xml".to_string(),
-                Some("txt") => "text/plain".to_string(),
-                Some("htm") => "text/html".to_string(),
-                Some("html") => "text/html".to_string(),
-                Some("pdf") => "application/pdf".to_string(),
-                Some("xml") => "application/xml".to_string(),
-                _ => "application/octet-stream".to_string(),
-            }
+            filename_content_type(&value)
         }
         Some(content_type) => content_type,
     };
     Ok(value)
 }
 
+/// Returns the content type of a file, guessed from its `filename` extension.
+fn filename_content_type(filename: &str) -> String {
+    match Path::new(filename).extension().and_then(OsStr::to_str) {
+        Some("gif") => "image/gif".to_string(),
+        Some("jpg") => "image/jpeg".to_string(),
+        Some("jpeg") => "image/jpeg".to_string(),
+        Some("png") => "image/png".to_string(),
+        Some("svg") => "image/svg+xml".to_string(),
+        Some("txt") => "text/plain".to_string(),
+        Some("htm") => "text/html".to_string(),
+        Some("html") => "text/html".to_string(),
+        Some("pdf") => "application/pdf".to_string(),
+        Some("xml") => "application/xml".to_string(),
+        _ => "application/octet-stream".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::SourceInfo;
@@ -16,17 +16,23 @@
  *
  */
 use std::collections::HashMap;
+use std::path::Path;
 use std::time::Duration;
 
 use hurl_core::ast::{
-    BooleanOption, Entry, EntryOption, Float, NaturalOption, Number as AstNumber, OptionKind,
-    Retry, RetryOption, SectionValue, VariableDefinition, VariableValue,
+    BooleanOption, DurationOption, Entry, EntryOption, Expr, Float, HttpVersion, IpVersion,
+    NaturalOption, Number as AstNumber, OptionKind, Retry, RetryOption, SectionValue, SourceInfo,
+    Template, VariableDefinition, VariableValue,
 };
+use serde::Serialize;
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{Body, IpResolve, Param, RequestedHttpVersion};
+use crate::runner::body::eval_file;
+use crate::runner::multipart::eval_form_option;
 use crate::runner::template::{eval_expression, eval_template};
 use crate::runner::{Error, Number, Output, RunnerError, RunnerOptions, Value};
 use crate::util::logger::{Logger, Verbosity};
+use crate::util::path::ContextDir;
 
 /// Returns a new [`RunnerOptions`] based on the `entry` optional Options section
 /// and a default `runner_options`.
@@ -45,38 +51,159 @@ pub fn get_entry_options(
     logger.debug("");
     logger.debug_important("Entry options:");
 
+    // The IP version explicitly requested by an option, and the name of this option.
+    let mut requested_ip = None;
+    // The options applied to this entry, logged as JSON in very verbose mode.
+    let mut applied_options = vec![];
+
     for section in &entry.request.sections {
         if let SectionValue::Options(options) = &section.value {
             for option in options.iter() {
                 match &option.kind {
-                    OptionKind::AwsSigV4(value) => {
+                    OptionKind::AbortOnError(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.abort_on_error = value;
+                    }
+                    OptionKind::Accept(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.accept = Some(value);
+                    }
+                    OptionKind::AcceptEncoding(value) => {
+                        let value = eval_template(value, variables)?;
+                        // Like `compressed`, the response body is decompressed on assert.
+                        runner_options.accept_encoding = Some(value);
+                        runner_options.compressed = true;
+                    }
+                    OptionKind::AwsRegion(value) => {
                         let value = eval_template(value, variables)?;
-                        runner_options.aws_sigv4 = Some(value);
+                        runner_options.aws_region = Some(value);
+                    }
+                    OptionKind::AwsService(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.aws_service = Some(value);
+                    }
+                    OptionKind::AwsSigV4(value) => {
+                        let aws_sigv4 = eval_template(value, variables)?;
+                        if !is_valid_aws_sigv4(&aws_sigv4) {
+                            let inner = RunnerError::InvalidAwsSigV4(aws_sigv4);
+                            return Err(Error::new(value.source_info, inner, false));
+                        }
+                        runner_options.aws_sigv4 = Some(aws_sigv4);
+                    }
+                    OptionKind::BodyFile(filename) => {
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_file(filename, variables, context_dir)?;
+                        let filename = eval_template(filename, variables)?;
+                        runner_options.body_file = Some(Body::File(value, filename));
                     }
                     OptionKind::CaCertificate(filename) => {
-                        let value = eval_template(filename, variables)?;
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_filename(filename, variables, context_dir)?;
                         runner_options.cacert_file = Some(value);
                     }
+                    OptionKind::CertType(value) => {
+                        runner_options.client_cert_type = Some(*value);
+                    }
                     OptionKind::ClientCert(filename) => {
-                        let value = eval_template(filename, variables)?;
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_filename(filename, variables, context_dir)?;
                         runner_options.client_cert_file = Some(value);
                     }
                     OptionKind::ClientKey(filename) => {
-                        let value = eval_template(filename, variables)?;
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_filename(filename, variables, context_dir)?;
                         runner_options.client_key_file = Some(value);
                     }
                     OptionKind::Compressed(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.compressed = value;
                     }
+                    OptionKind::ConnectRetries(value) => {
+                        let value = eval_retry_option(value, variables)?;
+                        runner_options.connect_retries = value;
+                    }
+                    OptionKind::ConnectTimeout(value) => {
+                        let value = eval_duration_option(value, variables)?;
+                        runner_options.connect_timeout = Duration::from_millis(value);
+                    }
                     OptionKind::ConnectTo(value) => {
                         let value = eval_template(value, variables)?;
+                        // A later mapping of the same source overrides the previous ones.
+                        let source = connect_to_source(&value);
+                        runner_options
+                            .connects_to
+                            .retain(|c| connect_to_source(c) != source);
                         runner_options.connects_to.push(value);
                     }
+                    OptionKind::ContentLength(value) => {
+                        runner_options.content_length = *value;
+                    }
+                    OptionKind::ContentType(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.content_type = Some(value);
+                    }
+                    OptionKind::ContinueOnError(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.continue_on_error = value;
+                    }
+                    OptionKind::Crlf(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.crlf = value;
+                    }
+                    OptionKind::DataBinary(filename) => {
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_file(filename, variables, context_dir)?;
+                        let filename = eval_template(filename, variables)?;
+                        runner_options.data_binary = Some(Body::File(value, filename));
+                    }
                     OptionKind::Delay(value) => {
-                        let value = eval_natural_option(value, variables)?;
+                        let value = eval_duration_option(value, variables)?;
                         runner_options.delay = Duration::from_millis(value);
                     }
+                    OptionKind::DnsCacheTtl(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.dns_cache_ttl = Some(Duration::from_secs(value));
+                    }
+                    OptionKind::Expect100Timeout(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.expect_100_timeout = Some(Duration::from_millis(value));
+                    }
+                    OptionKind::FailWithBody(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.fail_with_body = value;
+                    }
+                    OptionKind::FollowLocation(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.follow_location = value;
+                    }
+                    OptionKind::FollowLocationTrusted(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        if value {
+                            runner_options.follow_location = true;
+                        }
+                        runner_options.follow_location_trusted = value;
+                    }
+                    OptionKind::Form(value) => {
+                        let context_dir = &runner_options.context_dir;
+                        let param = eval_form_option(value, variables, context_dir)?;
+                        runner_options.form.push(param);
+                    }
+                    OptionKind::FreshConnect(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.fresh_connect = value;
+                    }
+                    OptionKind::GlobOff(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.globoff = value;
+                    }
+                    OptionKind::HappyEyeballsTimeout(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.happy_eyeballs_timeout = Some(Duration::from_millis(value));
+                    }
+                    OptionKind::HaproxyProtocol(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.haproxy_protocol = value;
+                    }
                     // HTTP version options (such as http1.0, http1.1, http2 etc...) are activated
                     // through a flag. In an `[Options]` section, the signification of such a flag is:
                     //
@@ -131,16 +258,13 @@ pub fn get_entry_options(
                             runner_options.http_version = RequestedHttpVersion::Http2;
                         }
                     }
-                    OptionKind::FollowLocation(value) => {
-                        let value = eval_boolean_option(value, variables)?;
-                        runner_options.follow_location = value;
-                    }
-                    OptionKind::FollowLocationTrusted(value) => {
-                        let value = eval_boolean_option(value, variables)?;
-                        if value {
-                            runner_options.follow_location = true;
-                        }
-                        runner_options.follow_location_trusted = value;
+                    OptionKind::HttpVersion(value) => {
+                        runner_options.http_version = match value {
+                            HttpVersion::Http10 => RequestedHttpVersion::Http10,
+                            HttpVersion::Http11 => RequestedHttpVersion::Http11,
+                            HttpVersion::Http2 => RequestedHttpVersion::Http2,
+                            HttpVersion::Http3 => RequestedHttpVersion::Http3,
+                        };
                     }
                     OptionKind::Insecure(value) => {
                         let value = eval_boolean_option(value, variables)?;
@@ -149,6 +273,7 @@ pub fn get_entry_options(
                     OptionKind::IpV4(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.ip_resolve = if value {
+                            check_ip_resolve(&mut requested_ip, IpResolve::IpV4, option)?;
                             IpResolve::IpV4
                         } else {
                             IpResolve::IpV6
@@ -157,40 +282,151 @@ pub fn get_entry_options(
                     OptionKind::IpV6(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.ip_resolve = if value {
+                            check_ip_resolve(&mut requested_ip, IpResolve::IpV6, option)?;
                             IpResolve::IpV6
                         } else {
                             IpResolve::IpV4
                         }
                     }
+                    OptionKind::IpVersion(value) => {
+                        let ip_resolve = match value {
+                            IpVersion::Auto => IpResolve::Default,
+                            IpVersion::V4 => IpResolve::IpV4,
+                            IpVersion::V6 => IpResolve::IpV6,
+                        };
+                        if ip_resolve != IpResolve::Default {
+                            check_ip_resolve(&mut requested_ip, ip_resolve, option)?;
+                        }
+                        runner_options.ip_resolve = ip_resolve;
+                    }
+                    OptionKind::Json(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.json = Some(value);
+                    }
+                    OptionKind::KeyPassword(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.client_key_password = Some(value);
+                    }
+                    OptionKind::KeyType(value) => {
+                        runner_options.client_key_type = Some(*value);
+                    }
+                    OptionKind::LimitRate(value) => {
+                        runner_options.limit_rate = Some(value.bytes());
+                    }
+                    OptionKind::LoginOptions(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.login_options = Some(value);
+                    }
+                    OptionKind::MaxFileSize(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.max_filesize = Some(value);
+                    }
+                    OptionKind::MaxHeaderSize(value) => {
+                        runner_options.max_header_size = Some(value.bytes());
+                    }
                     OptionKind::MaxRedirect(value) => {
                         let value = eval_natural_option(value, variables)?;
                         runner_options.max_redirect = Some(value as usize);
                     }
+                    OptionKind::MaxTime(value) => {
+                        let value = eval_duration_option(value, variables)?;
+                        runner_options.timeout = Duration::from_millis(value);
+                    }
                     OptionKind::NetRc(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.netrc = value;
                     }
                     OptionKind::NetRcFile(value) => {
-                        let filename = eval_template(value, variables)?;
+                        let context_dir = &runner_options.context_dir;
+                        let filename = eval_filename(value, variables, context_dir)?;
                         runner_options.netrc_file = Some(filename);
                     }
                     OptionKind::NetRcOptional(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.netrc_optional = value;
                     }
+                    OptionKind::NoBuffer(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.no_buffer = value;
+                    }
+                    OptionKind::NoProxy(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.no_proxy = Some(value);
+                    }
                     OptionKind::Output(output) => {
                         let filename = eval_template(output, variables)?;
                         let output = Output::new(&filename);
                         runner_options.output = Some(output);
                     }
+                    OptionKind::Parallel(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.parallel = value;
+                    }
                     OptionKind::PathAsIs(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.path_as_is = value;
                     }
+                    OptionKind::Post301(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.post301 = value;
+                    }
+                    OptionKind::Post302(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.post302 = value;
+                    }
+                    OptionKind::Post303(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.post303 = value;
+                    }
                     OptionKind::Proxy(value) => {
                         let value = eval_template(value, variables)?;
                         runner_options.proxy = Some(value);
                     }
+                    OptionKind::ProxyCaCert(filename) => {
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_filename(filename, variables, context_dir)?;
+                        runner_options.proxy_cacert_file = Some(value);
+                    }
+                    OptionKind::ProxyInsecure(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.proxy_insecure = value;
+                    }
+                    OptionKind::ProxyUser(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.proxy_user = Some(value);
+                    }
+                    OptionKind::Query(value) => {
+                        let param = eval_query_option(value, variables)?;
+                        runner_options.querystring.push(param);
+                    }
+                    OptionKind::Range(value) => {
+                        let range = eval_template(value, variables)?;
+                        if !is_valid_range(&range) {
+                            let inner = RunnerError::InvalidRange(range);
+                            return Err(Error::new(value.source_info, inner, false));
+                        }
+                        runner_options.range = Some(range);
+                    }
+                    OptionKind::RawResponse(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.raw = value;
+                    }
+                    OptionKind::Referer(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.referer = Some(value);
+                    }
+                    OptionKind::RemoveResolve(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.resolves.push(format!("-{value}"));
+                    }
+                    OptionKind::Repeat(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.repeat = value as usize;
+                    }
+                    OptionKind::RequestMethod(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.request_method = Some(value);
+                    }
                     OptionKind::Resolve(value) => {
                         let value = eval_template(value, variables)?;
                         runner_options.resolves.push(value);
@@ -199,24 +435,127 @@ pub fn get_entry_options(
                         let value = eval_retry_option(value, variables)?;
                         runner_options.retry = value;
                     }
+                    OptionKind::RetryDeadline(value) => {
+                        let value = eval_duration_option(value, variables)?;
+                        runner_options.retry_deadline = Some(Duration::from_millis(value));
+                    }
                     OptionKind::RetryInterval(value) => {
-                        let value = eval_natural_option(value, variables)?;
+                        let value = eval_duration_option(value, variables)?;
                         runner_options.retry_interval = Duration::from_millis(value);
                     }
+                    OptionKind::RetryJitter(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        if value > 100 {
+                            let source_info = SourceInfo::new(
+                                option.space2.source_info.end,
+                                option.line_terminator0.space0.source_info.start,
+                            );
+                            let inner = RunnerError::InvalidRetryJitter(value);
+                            return Err(Error::new(source_info, inner, false));
+                        }
+                        runner_options.retry_jitter = value;
+                    }
+                    OptionKind::RetryOn(value) => {
+                        runner_options.retry_on = *value;
+                    }
+                    OptionKind::RetryOnStatus(value) => {
+                        runner_options.retry_on_status = value.clone();
+                    }
+                    OptionKind::SendFragment(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.send_fragment = value;
+                    }
                     OptionKind::Skip(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.skip = value;
                     }
+                    OptionKind::SpeedLimit(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        let Ok(value) = u32::try_from(value) else {
+                            let source_info = SourceInfo::new(
+                                option.space2.source_info.end,
+                                option.line_terminator0.space0.source_info.start,
+                            );
+                            let inner = RunnerError::InvalidSpeedLimit(value);
+                            return Err(Error::new(source_info, inner, false));
+                        };
+                        runner_options.speed_limit = Some(value);
+                    }
+                    OptionKind::SpeedTime(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.speed_time = Some(Duration::from_secs(value));
+                    }
+                    OptionKind::SshPrivateKey(filename) => {
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_filename(filename, variables, context_dir)?;
+                        runner_options.ssh_private_key_file = Some(value);
+                    }
+                    OptionKind::SshPublicKey(filename) => {
+                        let context_dir = &runner_options.context_dir;
+                        let value = eval_filename(filename, variables, context_dir)?;
+                        runner_options.ssh_public_key_file = Some(value);
+                    }
+                    OptionKind::StopOnFailure(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.stop_on_failure = value;
+                    }
+                    OptionKind::TcpKeepAlive(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.tcp_keepalive = value;
+                    }
+                    OptionKind::TcpKeepIdle(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.tcp_keepidle = Some(Duration::from_secs(value));
+                    }
+                    OptionKind::TcpKeepIntvl(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.tcp_keepintvl = Some(Duration::from_secs(value));
+                    }
+                    OptionKind::TcpNoDelay(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        runner_options.tcp_nodelay = value;
+                    }
+                    OptionKind::TlsMax(value) => {
+                        runner_options.tls_max = Some(*value);
+                    }
+                    OptionKind::TraceBody(value) => {
+                        runner_options.trace_body = Some(*value);
+                    }
                     OptionKind::UnixSocket(value) => {
                         let value = eval_template(value, variables)?;
                         runner_options.unix_socket = Some(value);
                     }
+                    OptionKind::Url(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.url = Some(value);
+                    }
                     OptionKind::User(value) => {
                         let value = eval_template(value, variables)?;
                         runner_options.user = Some(value);
                     }
-                    OptionKind::Variable(VariableDefinition { name, value, .. }) => {
-                        let value = eval_variable_value(value, variables)?;
+                    OptionKind::UserAgent(value) => {
+                        let value = eval_template(value, variables)?;
+                        runner_options.user_agent = Some(value);
+                    }
+                    OptionKind::Variable(VariableDefinition {
+                        name,
+                        space1,
+                        value,
+                        ..
+                    })
+                    | OptionKind::LocalVariable(VariableDefinition {
+                        name,
+                        space1,
+                        value,
+                        ..
+                    }) => {
+                        let context_dir = &runner_options.context_dir;
+                        // Values like `env,HOME` have no position: errors are reported at the
+                        // start of the variable value.
+                        let pos = space1.source_info.end;
+                        let source_info = SourceInfo::new(pos, pos);
+                        let value =
+                            eval_variable_value(value, variables, context_dir, source_info)?;
                         variables.insert(name.clone(), value);
                     }
                     // verbose and very-verbose option have been previously processed as they
@@ -225,24 +564,203 @@ pub fn get_entry_options(
                     OptionKind::Verbose(value) => {
                         eval_boolean_option(value, variables)?;
                     }
+                    OptionKind::VerboseLevel(_) => {}
                     OptionKind::VeryVerbose(value) => {
                         eval_boolean_option(value, variables)?;
                     }
                 }
                 log_option(option, logger);
+                applied_options.push(applied_option(option, variables)?);
             }
         }
     }
+    if logger.verbosity == Some(Verbosity::VeryVerbose) {
+        let json = serde_json::to_string(&applied_options).unwrap();
+        logger.debug(&format!("Entry options (JSON): {json}"));
+    }
     Ok(runner_options)
 }
 
-/// Logs an entry option.
+/// Logs an entry option. The value of a secret option, like user credentials, is masked.
 fn log_option(option: &EntryOption, logger: &mut Logger) {
     let name = option.kind.name();
-    let value = option.kind.value_as_str();
+    let value = if option.kind.is_secret() {
+        "***".to_string()
+    } else {
+        option.kind.value_as_str()
+    };
     logger.debug(&format!("{name}: {value}"));
 }
 
+/// An option applied to an entry, with its value resolved against the variables.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct AppliedOption {
+    pub name: String,
+    pub value: String,
+}
+
+/// Returns the applied `option`, with its value evaluated against `variables`. Like in logs, the
+/// value of a secret option is masked.
+fn applied_option(
+    option: &EntryOption,
+    variables: &HashMap<String, Value>,
+) -> Result<AppliedOption, Error> {
+    let name = option.kind.name().to_string();
+    let value = if option.kind.is_secret() {
+        "***".to_string()
+    } else {
+        eval_option_value(&option.kind, variables)?
+    };
+    Ok(AppliedOption { name, value })
+}
+
+/// Evaluates the value of an option `kind` to its string representation.
+fn eval_option_value(
+    kind: &OptionKind,
+    variables: &HashMap<String, Value>,
+) -> Result<String, Error> {
+    let value = match kind {
+        OptionKind::Accept(value)
+        | OptionKind::AcceptEncoding(value)
+        | OptionKind::AwsRegion(value)
+        | OptionKind::AwsService(value)
+        | OptionKind::AwsSigV4(value)
+        | OptionKind::BodyFile(value)
+        | OptionKind::CaCertificate(value)
+        | OptionKind::ClientCert(value)
+        | OptionKind::ClientKey(value)
+        | OptionKind::ConnectTo(value)
+        | OptionKind::ContentType(value)
+        | OptionKind::Form(value)
+        | OptionKind::Json(value)
+        | OptionKind::KeyPassword(value)
+        | OptionKind::LoginOptions(value)
+        | OptionKind::NetRcFile(value)
+        | OptionKind::NoProxy(value)
+        | OptionKind::Output(value)
+        | OptionKind::Proxy(value)
+        | OptionKind::ProxyCaCert(value)
+        | OptionKind::ProxyUser(value)
+        | OptionKind::Query(value)
+        | OptionKind::Range(value)
+        | OptionKind::Referer(value)
+        | OptionKind::RequestMethod(value)
+        | OptionKind::Resolve(value)
+        | OptionKind::SshPrivateKey(value)
+        | OptionKind::SshPublicKey(value)
+        | OptionKind::UnixSocket(value)
+        | OptionKind::Url(value)
+        | OptionKind::User(value)
+        | OptionKind::UserAgent(value) => eval_template(value, variables)?,
+        OptionKind::DataBinary(value) => format!("@{}", eval_template(value, variables)?),
+        OptionKind::RemoveResolve(value) => format!("-{}", eval_template(value, variables)?),
+        OptionKind::AbortOnError(value)
+        | OptionKind::Compressed(value)
+        | OptionKind::ContinueOnError(value)
+        | OptionKind::Crlf(value)
+        | OptionKind::FailWithBody(value)
+        | OptionKind::FollowLocation(value)
+        | OptionKind::FollowLocationTrusted(value)
+        | OptionKind::FreshConnect(value)
+        | OptionKind::GlobOff(value)
+        | OptionKind::HaproxyProtocol(value)
+        | OptionKind::Http10(value)
+        | OptionKind::Http11(value)
+        | OptionKind::Http2(value)
+        | OptionKind::Http3(value)
+        | OptionKind::Insecure(value)
+        | OptionKind::IpV4(value)
+        | OptionKind::IpV6(value)
+        | OptionKind::NetRc(value)
+        | OptionKind::NetRcOptional(value)
+        | OptionKind::NoBuffer(value)
+        | OptionKind::Parallel(value)
+        | OptionKind::PathAsIs(value)
+        | OptionKind::Post301(value)
+        | OptionKind::Post302(value)
+        | OptionKind::Post303(value)
+        | OptionKind::ProxyInsecure(value)
+        | OptionKind::RawResponse(value)
+        | OptionKind::SendFragment(value)
+        | OptionKind::Skip(value)
+        | OptionKind::StopOnFailure(value)
+        | OptionKind::TcpKeepAlive(value)
+        | OptionKind::TcpNoDelay(value)
+        | OptionKind::Verbose(value)
+        | OptionKind::VeryVerbose(value) => eval_boolean_option(value, variables)?.to_string(),
+        OptionKind::ConnectTimeout(value)
+        | OptionKind::Delay(value)
+        | OptionKind::MaxTime(value)
+        | OptionKind::RetryDeadline(value)
+        | OptionKind::RetryInterval(value) => eval_duration_option(value, variables)?.to_string(),
+        OptionKind::DnsCacheTtl(value)
+        | OptionKind::Expect100Timeout(value)
+        | OptionKind::HappyEyeballsTimeout(value)
+        | OptionKind::MaxFileSize(value)
+        | OptionKind::MaxRedirect(value)
+        | OptionKind::Repeat(value)
+        | OptionKind::RetryJitter(value)
+        | OptionKind::SpeedLimit(value)
+        | OptionKind::SpeedTime(value)
+        | OptionKind::TcpKeepIdle(value)
+        | OptionKind::TcpKeepIntvl(value) => eval_natural_option(value, variables)?.to_string(),
+        OptionKind::ConnectRetries(value) | OptionKind::Retry(value) => {
+            eval_retry_option(value, variables)?.to_string()
+        }
+        OptionKind::CertType(_)
+        | OptionKind::ContentLength(_)
+        | OptionKind::HttpVersion(_)
+        | OptionKind::IpVersion(_)
+        | OptionKind::KeyType(_)
+        | OptionKind::LimitRate(_)
+        | OptionKind::LocalVariable(_)
+        | OptionKind::MaxHeaderSize(_)
+        | OptionKind::RetryOn(_)
+        | OptionKind::RetryOnStatus(_)
+        | OptionKind::TlsMax(_)
+        | OptionKind::TraceBody(_)
+        | OptionKind::Variable(_)
+        | OptionKind::VerboseLevel(_) => kind.value_as_str(),
+    };
+    Ok(value)
+}
+
+/// Returns the names of the variables defined by the `variable-local` options of this `entry`,
+/// with their current value in `variables`.
+///
+/// These values are restored with [`restore_local_variables`] once the entry has run.
+pub fn get_local_variables(
+    entry: &Entry,
+    variables: &HashMap<String, Value>,
+) -> Vec<(String, Option<Value>)> {
+    let mut local_variables = vec![];
+    for section in &entry.request.sections {
+        if let SectionValue::Options(options) = &section.value {
+            for option in options {
+                if let OptionKind::LocalVariable(VariableDefinition { name, .. }) = &option.kind {
+                    local_variables.push((name.clone(), variables.get(name).cloned()));
+                }
+            }
+        }
+    }
+    local_variables
+}
+
+/// Restores the `variables` overridden by `variable-local` options: a variable is reset to the
+/// value it had before the entry, or removed if it was not defined.
+pub fn restore_local_variables(
+    local_variables: Vec<(String, Option<Value>)>,
+    variables: &mut HashMap<String, Value>,
+) {
+    // A variable can be defined by several options: the first saved value is the one to restore.
+    for (name, value) in local_variables.into_iter().rev() {
+        match value {
+            Some(value) => variables.insert(name, value),
+            None => variables.remove(&name),
+        };
+    }
+}
+
 /// Returns [`true`] if this `entry` has an Option section, [`false`] otherwise.
 fn has_options(entry: &Entry) -> bool {
     entry
@@ -272,6 +790,7 @@ pub fn get_entry_verbosity(
                             None
                         }
                     }
+                    OptionKind::VerboseLevel(level) => verbosity = verbosity_from_level(*level),
                     OptionKind::VeryVerbose(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         verbosity = if value {
@@ -288,6 +807,39 @@ pub fn get_entry_verbosity(
     Ok(verbosity)
 }
 
+/// Checks that `ip_resolve`, requested by `option`, doesn't conflict with the IP version
+/// `requested` by a previous option of the entry (templated values can only be checked at runtime).
+fn check_ip_resolve(
+    requested: &mut Option<(IpResolve, &'static str)>,
+    ip_resolve: IpResolve,
+    option: &EntryOption,
+) -> Result<(), Error> {
+    if let Some((previous, other)) = *requested {
+        if previous != ip_resolve {
+            let source_info = SourceInfo::new(
+                option.space0.source_info.end,
+                option.space1.source_info.start,
+            );
+            let inner = RunnerError::ConflictingOption {
+                name: option.kind.name().to_string(),
+                other: other.to_string(),
+            };
+            return Err(Error::new(source_info, inner, false));
+        }
+    }
+    *requested = Some((ip_resolve, option.kind.name()));
+    Ok(())
+}
+
+/// Returns the verbosity of a `verbose` option `level`.
+fn verbosity_from_level(level: u8) -> Option<Verbosity> {
+    match level {
+        0 => None,
+        1 => Some(Verbosity::Verbose),
+        _ => Some(Verbosity::VeryVerbose),
+    }
+}
+
 fn eval_boolean_option(
     boolean_value: &BooleanOption,
     variables: &HashMap<String, Value>,
@@ -296,6 +848,9 @@ fn eval_boolean_option(
         BooleanOption::Literal(value) => Ok(*value),
         BooleanOption::Expression(expr) => match eval_expression(expr, variables)? {
             Value::Bool(value) => Ok(value),
+            // A rendered `true` / `false` string is also accepted (for instance from an env var).
+            Value::String(value) if value == "true" => Ok(true),
+            Value::String(value) if value == "false" => Ok(false),
             v => {
                 let inner = RunnerError::TemplateVariableInvalidType {
                     name: expr.variable.name.clone(),
@@ -314,28 +869,43 @@ fn eval_natural_option(
 ) -> Result<u64, Error> {
     match natural_value {
         NaturalOption::Literal(value) => Ok(*value),
-        NaturalOption::Expression(expr) => match eval_expression(expr, variables)? {
-            Value::Number(Number::Integer(value)) => {
-                if value < 0 {
-                    let inner = RunnerError::TemplateVariableInvalidType {
-                        name: expr.variable.name.clone(),
-                        value: value.to_string(),
-                        expecting: "positive integer".to_string(),
-                    };
-                    Err(Error::new(expr.variable.source_info, inner, false))
-                } else {
-                    Ok(value as u64)
-                }
-            }
-            v => {
+        NaturalOption::Expression(expr) => eval_natural_expr(expr, variables),
+    }
+}
+
+/// Evaluates a duration option to a number of milliseconds.
+fn eval_duration_option(
+    duration_value: &DurationOption,
+    variables: &HashMap<String, Value>,
+) -> Result<u64, Error> {
+    match duration_value {
+        DurationOption::Literal(value) => Ok(value.millis),
+        DurationOption::Expression(expr) => eval_natural_expr(expr, variables),
+    }
+}
+
+fn eval_natural_expr(expr: &Expr, variables: &HashMap<String, Value>) -> Result<u64, Error> {
+    match eval_expression(expr, variables)? {
+        Value::Number(Number::Integer(value)) => {
+            if value < 0 {
                 let inner = RunnerError::TemplateVariableInvalidType {
                     name: expr.variable.name.clone(),
-                    value: v.to_string(),
+                    value: value.to_string(),
                     expecting: "positive integer".to_string(),
                 };
                 Err(Error::new(expr.variable.source_info, inner, false))
+            } else {
+                Ok(value as u64)
             }
-        },
+        }
+        v => {
+            let inner = RunnerError::TemplateVariableInvalidType {
+                name: expr.variable.name.clone(),
+                value: v.to_string(),
+                expecting: "positive integer".to_string(),
+            };
+            Err(Error::new(expr.variable.source_info, inner, false))
+        }
     }
 }
 
@@ -374,9 +944,22 @@ fn eval_retry_option(
     }
 }
 
+/// Evaluates a `variable_value`.
+///
+/// A variable read from a file (`file,secrets/token.txt;`) is read at execution time and its
+/// trailing newline is trimmed. A file that can't be read (not found, not authorized) or that is
+/// not valid UTF-8 is a runtime error.
+///
+/// A variable read from the environment (`env,HOME`) is resolved at execution time. An unset
+/// environment variable is a runtime error, reported at `source_info`.
+///
+/// If a default is given (`env,PORT:8080`), it's used when the file is missing or the environment
+/// variable is not set.
 fn eval_variable_value(
     variable_value: &VariableValue,
     variables: &mut HashMap<String, Value>,
+    context_dir: &ContextDir,
+    source_info: SourceInfo,
 ) -> Result<Value, Error> {
     match variable_value {
         VariableValue::Null => Ok(Value::Null),
@@ -386,6 +969,61 @@ fn eval_variable_value(
             let s = eval_template(template, variables)?;
             Ok(Value::String(s))
         }
+        VariableValue::List(values) => {
+            let values = values
+                .iter()
+                .map(|value| eval_variable_value(value, variables, context_dir, source_info))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::List(values))
+        }
+        VariableValue::Object(entries) => {
+            let entries = entries
+                .iter()
+                .map(|(key, value)| {
+                    let value = eval_variable_value(value, variables, context_dir, source_info)?;
+                    Ok((key.clone(), value))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            Ok(Value::Object(entries))
+        }
+        VariableValue::File(file, default) => {
+            match eval_file(&file.filename, variables, context_dir) {
+                Ok(bytes) => match String::from_utf8(bytes) {
+                    Ok(value) => Ok(Value::String(trim_newline(&value).to_string())),
+                    Err(_) => {
+                        let inner = RunnerError::InvalidDecoding {
+                            charset: "utf-8".to_string(),
+                        };
+                        Err(Error::new(file.filename.source_info, inner, false))
+                    }
+                },
+                // A missing file falls back to the default value, if any.
+                Err(error) => match (default, &error.inner) {
+                    (Some(default), RunnerError::FileReadAccess { .. }) => {
+                        eval_variable_value(default, variables, context_dir, source_info)
+                    }
+                    _ => Err(error),
+                },
+            }
+        }
+        VariableValue::Env(name, default) => match (std::env::var(name), default) {
+            (Ok(value), _) => Ok(Value::String(value)),
+            (Err(_), Some(default)) => {
+                eval_variable_value(default, variables, context_dir, source_info)
+            }
+            (Err(_), None) => {
+                let inner = RunnerError::EnvVariableNotDefined { name: name.clone() };
+                Err(Error::new(source_info, inner, false))
+            }
+        },
+    }
+}
+
+/// Removes one trailing newline (`\n` or `\r\n`) from `value`.
+fn trim_newline(value: &str) -> &str {
+    match value.strip_suffix('\n') {
+        Some(value) => value.strip_suffix('\r').unwrap_or(value),
+        None => value,
     }
 }
 
@@ -397,12 +1035,114 @@ fn eval_number(number: &AstNumber) -> Value {
     }
 }
 
+/// Returns the `HOST1:PORT1` source of a `HOST1:PORT1:HOST2:PORT2` connect-to value.
+fn connect_to_source(value: &str) -> &str {
+    match value.match_indices(':').nth(1) {
+        Some((index, _)) => &value[..index],
+        None => value,
+    }
+}
+
+/// Returns `true` if the AWS SigV4 option `value` has a non-empty provider, the first of its
+/// colon-separated `provider1[:provider2[:region[:service]]]` parts.
+fn is_valid_aws_sigv4(value: &str) -> bool {
+    value
+        .split(':')
+        .next()
+        .is_some_and(|provider| !provider.is_empty())
+}
+
+/// Returns the query parameter of the `query` option, a `name=value` pair.
+fn eval_query_option(query: &Template, variables: &HashMap<String, Value>) -> Result<Param, Error> {
+    let value = eval_template(query, variables)?;
+    let Some((name, value)) = value.split_once('=') else {
+        let inner = RunnerError::InvalidQueryOption { value };
+        return Err(Error::new(query.source_info, inner, false));
+    };
+    Ok(Param {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Evaluates the `filename` of a certificate, key or netrc option, expanding a leading `~` to the
+/// home directory of the current user.
+///
+/// A relative filename is resolved against the file root of `context_dir` (by default, the
+/// directory of the Hurl file), and not against the current directory.
+fn eval_filename(
+    filename: &Template,
+    variables: &HashMap<String, Value>,
+    context_dir: &ContextDir,
+) -> Result<String, Error> {
+    let value = eval_template(filename, variables)?;
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok();
+    let value = expand_home(&value, home.as_deref())
+        .map_err(|inner| Error::new(filename.source_info, inner, false))?;
+    let path = context_dir.resolved_path(Path::new(&value));
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Expands a leading `~` of `path` to the `home` directory.
+///
+/// Only the home directory of the current user is supported: a `~user` path is rejected.
+fn expand_home(path: &str, home: Option<&str>) -> Result<String, RunnerError> {
+    let Some(rest) = path.strip_prefix('~') else {
+        return Ok(path.to_string());
+    };
+    if !rest.is_empty() && !rest.starts_with(['/', '\\']) {
+        return Err(RunnerError::UnsupportedHomeDirectory(path.to_string()));
+    }
+    match home {
+        Some(home) => Ok(format!("{home}{rest}")),
+        None => Err(RunnerError::EnvVariableNotDefined {
+            name: "HOME".to_string(),
+        }),
+    }
+}
+
+/// Returns `true` if `range` follows curl byte range syntax: a comma-separated list of `N-M`,
+/// `N-` or `-N` ranges.
+fn is_valid_range(range: &str) -> bool {
+    let is_number = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    range.split(',').all(|r| match r.split_once('-') {
+        Some((start, "")) => is_number(start),
+        Some(("", end)) => is_number(end),
+        Some((start, end)) => is_number(start) && is_number(end),
+        None => false,
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{Expr, Pos, SourceInfo, Variable, Whitespace};
+    use std::path::{Path, PathBuf};
+
+    use hurl_core::ast::{Expr, File, Pos, Template, TemplateElement, Variable, Whitespace};
+    use hurl_core::parser;
 
     use super::*;
-    use crate::runner::RunnerError;
+    use crate::runner::{RunnerError, RunnerOptionsBuilder};
+    use crate::util::logger::LoggerOptionsBuilder;
+    use crate::util::term::{Stderr, WriteMode};
+
+    /// Returns a logger with `verbosity`, writing to a buffer.
+    fn new_logger(verbosity: Option<Verbosity>) -> Logger {
+        let logger_options = LoggerOptionsBuilder::new().verbosity(verbosity).build();
+        Logger::new(&logger_options, Stderr::new(WriteMode::Buffered))
+    }
+
+    /// Returns the options of the first entry of `content`, evaluated against `variables`.
+    fn entry_options(
+        content: &str,
+        variables: &mut HashMap<String, Value>,
+        logger: &mut Logger,
+    ) -> Result<RunnerOptions, Error> {
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let entry = &hurl_file.entries[0];
+        get_entry_options(entry, &RunnerOptions::default(), variables, logger)
+    }
 
     fn verbose_option_template() -> BooleanOption {
         // {{verbose}}
@@ -440,6 +1180,429 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_get_entry_options_multiple_resolves_and_connects_to() {
+        let content = r#"GET http://foo.com/hello
+[Options]
+resolve: foo.com:80:127.0.0.1
+resolve: bar.com:80:127.0.0.2
+connect-to: foo.com:80:localhost:8000
+connect-to: bar.com:80:localhost:8001
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(
+            options.resolves,
+            vec![
+                "foo.com:80:127.0.0.1".to_string(),
+                "bar.com:80:127.0.0.2".to_string()
+            ]
+        );
+        assert_eq!(
+            options.connects_to,
+            vec![
+                "foo.com:80:localhost:8000".to_string(),
+                "bar.com:80:localhost:8001".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_multiple_queries() {
+        let content = r#"GET http://foo.com/items?sort=asc
+[Options]
+query: page=2
+query: "q={{name}} & co"
+"#;
+        let mut variables = HashMap::default();
+        variables.insert("name".to_string(), Value::String("Bob".to_string()));
+        let mut logger = new_logger(None);
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(
+            options.querystring,
+            vec![
+                Param {
+                    name: "page".to_string(),
+                    value: "2".to_string(),
+                },
+                Param {
+                    name: "q".to_string(),
+                    value: "Bob & co".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_json_logged() {
+        let content = r#"GET http://localhost:8000/hello
+[Options]
+retry: {{retry}}
+retry-interval: 500
+insecure: true
+user: bob:{{password}}
+variable: name=bob
+output: {{name}}.json
+"#;
+        let mut variables = HashMap::default();
+        variables.insert("retry".to_string(), Value::Number(Number::Integer(3)));
+        variables.insert("password".to_string(), Value::String("secret".to_string()));
+        let mut logger = new_logger(Some(Verbosity::VeryVerbose));
+
+        entry_options(content, &mut variables, &mut logger).unwrap();
+        let output = logger.stderr().buffer();
+        assert!(output.contains(
+            r#"Entry options (JSON): [{"name":"retry","value":"3"},{"name":"retry-interval","value":"500"},{"name":"insecure","value":"true"},{"name":"user","value":"***"},{"name":"variable","value":"name=bob"},{"name":"output","value":"bob.json"}]"#
+        ));
+        assert!(!output.contains("secret"));
+    }
+
+    #[test]
+    fn test_get_entry_options_json_not_logged_in_verbose() {
+        let content = r#"GET http://localhost:8000/hello
+[Options]
+insecure: true
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(Some(Verbosity::Verbose));
+
+        entry_options(content, &mut variables, &mut logger).unwrap();
+        let output = logger.stderr().buffer();
+        assert!(output.contains("insecure: true"));
+        assert!(!output.contains("Entry options (JSON)"));
+    }
+
+    #[test]
+    fn test_local_variables() {
+        let content = r#"GET http://foo.com/items/{{id}}
+[Options]
+variable-local: id=1
+variable-local: user=alice
+variable: page=2
+
+GET http://foo.com/items
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let runner_options = RunnerOptions::default();
+        let mut variables = HashMap::default();
+        variables.insert("user".to_string(), Value::String("bob".to_string()));
+        let mut logger = new_logger(None);
+
+        let entry = &hurl_file.entries[0];
+        let local_variables = get_local_variables(entry, &variables);
+        get_entry_options(entry, &runner_options, &mut variables, &mut logger).unwrap();
+        assert_eq!(variables.get("id").unwrap().to_string(), "1".to_string());
+        assert_eq!(
+            variables.get("user").unwrap().to_string(),
+            "alice".to_string()
+        );
+
+        // Once the entry has run, only the global variables are kept for the next entry.
+        restore_local_variables(local_variables, &mut variables);
+        let entry = &hurl_file.entries[1];
+        get_entry_options(entry, &runner_options, &mut variables, &mut logger).unwrap();
+        assert!(!variables.contains_key("id"));
+        assert_eq!(
+            variables.get("user").unwrap().to_string(),
+            "bob".to_string()
+        );
+        assert_eq!(variables.get("page").unwrap().to_string(), "2".to_string());
+    }
+
+    #[test]
+    fn test_get_entry_options_invalid_query() {
+        let content = r#"GET http://foo.com/items
+[Options]
+query: page
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let error = entry_options(content, &mut variables, &mut logger).unwrap_err();
+        assert_eq!(
+            error.inner,
+            RunnerError::InvalidQueryOption {
+                value: "page".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_retry_jitter_out_of_range() {
+        let content = r#"GET http://foo.com/items
+[Options]
+retry-jitter: {{jitter}}
+"#;
+        let mut variables = HashMap::default();
+        variables.insert("jitter".to_string(), Value::Number(Number::Integer(150)));
+        let mut logger = new_logger(None);
+
+        let error = entry_options(content, &mut variables, &mut logger).unwrap_err();
+        assert_eq!(error.inner, RunnerError::InvalidRetryJitter(150));
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(3, 15), Pos::new(3, 25))
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_speed_limit_out_of_range() {
+        let content = r#"GET http://foo.com/items
+[Options]
+speed-limit: 4294967296
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let error = entry_options(content, &mut variables, &mut logger).unwrap_err();
+        assert_eq!(error.inner, RunnerError::InvalidSpeedLimit(4_294_967_296));
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(3, 14), Pos::new(3, 24))
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_resolve_wildcard_port() {
+        let content = r#"GET http://example.com/hello
+[Options]
+resolve: example.com:*:127.0.0.1
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(
+            options.resolves,
+            vec!["example.com:*:127.0.0.1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_remove_resolve() {
+        let content = r#"GET http://example.com/hello
+[Options]
+resolve: example.com:443:127.0.0.1
+resolve: -example.com:443
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(
+            options.resolves,
+            vec![
+                "example.com:443:127.0.0.1".to_string(),
+                "-example.com:443".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_key_password_not_logged() {
+        let content = r#"GET http://foo.com/hello
+[Options]
+key-password: s3cr3t
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(Some(Verbosity::Verbose));
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(options.client_key_password, Some("s3cr3t".to_string()));
+        let output = logger.stderr().buffer();
+        assert!(output.contains("key-password: ***"));
+        assert!(!output.contains("s3cr3t"));
+    }
+
+    #[test]
+    fn test_get_entry_options_user_not_logged() {
+        let content = r#"GET http://foo.com/hello
+[Options]
+user: bob:secret
+verbose: true
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(Some(Verbosity::Verbose));
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(options.user, Some("bob:secret".to_string()));
+        let output = logger.stderr().buffer();
+        assert!(output.contains("user: ***"));
+        assert!(output.contains("verbose: true"));
+        assert!(!output.contains("bob:secret"));
+    }
+
+    #[test]
+    fn test_get_entry_options_conflicting_ip_versions() {
+        let content = r#"GET http://foo.com/hello
+[Options]
+ipv4: {{ipv4}}
+ip-version: 6
+"#;
+        let mut variables = HashMap::default();
+        variables.insert("ipv4".to_string(), Value::Bool(true));
+        let mut logger = new_logger(None);
+
+        let error = entry_options(content, &mut variables, &mut logger)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(4, 1), Pos::new(4, 11))
+        );
+        assert_eq!(
+            error.inner,
+            RunnerError::ConflictingOption {
+                name: "ip-version".to_string(),
+                other: "ipv4".to_string()
+            }
+        );
+
+        // Without conflict, the last option is used.
+        variables.insert("ipv4".to_string(), Value::Bool(false));
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(options.ip_resolve, IpResolve::IpV6);
+    }
+
+    #[test]
+    fn test_connect_to_source() {
+        assert_eq!(
+            connect_to_source("example.com:443:host-47.example.com:443"),
+            "example.com:443"
+        );
+        assert_eq!(connect_to_source("example.com"), "example.com");
+    }
+
+    #[test]
+    fn test_is_valid_aws_sigv4() {
+        assert!(is_valid_aws_sigv4("aws:amz:eu-central-1:sts"));
+        assert!(is_valid_aws_sigv4("aws"));
+        assert!(!is_valid_aws_sigv4(""));
+        assert!(!is_valid_aws_sigv4(":amz:eu-central-1:sts"));
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = Some("/home/bob");
+        assert_eq!(expand_home("~/x", home).unwrap(), "/home/bob/x");
+        assert_eq!(expand_home("~", home).unwrap(), "/home/bob");
+        assert_eq!(expand_home("/etc/ca.pem", home).unwrap(), "/etc/ca.pem");
+        assert_eq!(
+            expand_home("certs/~/ca.pem", home).unwrap(),
+            "certs/~/ca.pem"
+        );
+        assert_eq!(
+            expand_home("~alice/ca.pem", home).unwrap_err(),
+            RunnerError::UnsupportedHomeDirectory("~alice/ca.pem".to_string())
+        );
+        assert_eq!(
+            expand_home("~/x", None).unwrap_err(),
+            RunnerError::EnvVariableNotDefined {
+                name: "HOME".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_entry_options_cacert_home() {
+        let Ok(home) = std::env::var("HOME") else {
+            return;
+        };
+        let content = "GET http://localhost:8000/hello\n[Options]\ncacert: ~/x\n";
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(options.cacert_file, Some(format!("{home}/x")));
+    }
+
+    #[test]
+    fn test_get_entry_options_filenames_relative_to_file_root() {
+        let content = r#"GET http://localhost:8000/hello
+[Options]
+cacert: certs/ca.pem
+cert: certs/client.pem
+key: /etc/keys/client.key
+netrc-file: .netrc
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let entry = &hurl_file.entries[0];
+        // The Hurl file is run from another directory than its own.
+        let context_dir = ContextDir::new(Path::new("/tmp/elsewhere"), Path::new("/project/tests"));
+        let runner_options = RunnerOptionsBuilder::new()
+            .context_dir(&context_dir)
+            .build();
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let options =
+            get_entry_options(entry, &runner_options, &mut variables, &mut logger).unwrap();
+        let path = |s: &str| Some(Path::new(s).to_string_lossy().to_string());
+        assert_eq!(options.cacert_file, path("/project/tests/certs/ca.pem"));
+        assert_eq!(
+            options.client_cert_file,
+            path("/project/tests/certs/client.pem")
+        );
+        assert_eq!(options.client_key_file, path("/etc/keys/client.key"));
+        assert_eq!(options.netrc_file, path("/project/tests/.netrc"));
+    }
+
+    #[test]
+    fn test_get_entry_options_durations() {
+        let content = r#"GET http://localhost:8000/hello
+[Options]
+connect-timeout: 5s
+delay: 500ms
+max-time: 1m
+retry-interval: 250
+"#;
+        let mut variables = HashMap::default();
+        let mut logger = new_logger(None);
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert_eq!(options.connect_timeout, Duration::from_secs(5));
+        assert_eq!(options.delay, Duration::from_millis(500));
+        assert_eq!(options.timeout, Duration::from_secs(60));
+        assert_eq!(options.retry_interval, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_get_entry_options_parallel() {
+        let content = r#"GET http://localhost:8000/hello
+[Options]
+parallel: {{independent}}
+"#;
+        assert!(!RunnerOptions::default().parallel);
+        let mut variables = HashMap::default();
+        variables.insert("independent".to_string(), Value::Bool(true));
+        let mut logger = new_logger(None);
+
+        let options = entry_options(content, &mut variables, &mut logger).unwrap();
+        assert!(options.parallel);
+    }
+
+    #[test]
+    fn test_is_valid_range() {
+        assert!(is_valid_range("0-1023"));
+        assert!(is_valid_range("1024-"));
+        assert!(is_valid_range("-500"));
+        assert!(is_valid_range("0-99,200-299"));
+        assert!(!is_valid_range(""));
+        assert!(!is_valid_range("-"));
+        assert!(!is_valid_range("abc"));
+        assert!(!is_valid_range("0-1023,"));
+    }
+
+    #[test]
+    fn test_verbosity_from_level() {
+        assert_eq!(verbosity_from_level(0), None);
+        assert_eq!(verbosity_from_level(1), Some(Verbosity::Verbose));
+        assert_eq!(verbosity_from_level(2), Some(Verbosity::VeryVerbose));
+    }
+
     #[test]
     fn test_eval_boolean_option() {
         let mut variables = HashMap::default();
@@ -447,6 +1610,9 @@ mod tests {
 
         variables.insert("verbose".to_string(), Value::Bool(true));
         assert!(eval_boolean_option(&verbose_option_template(), &variables).unwrap());
+
+        variables.insert("verbose".to_string(), Value::String("false".to_string()));
+        assert!(!eval_boolean_option(&verbose_option_template(), &variables).unwrap());
     }
 
     #[test]
@@ -475,6 +1641,193 @@ mod tests {
                 expecting: "boolean".to_string()
             }
         );
+
+        variables.insert("verbose".to_string(), Value::String("yes".to_string()));
+        let error = eval_boolean_option(&verbose_option_template(), &variables)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::TemplateVariableInvalidType {
+                name: "verbose".to_string(),
+                value: "yes".to_string(),
+                expecting: "boolean".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_trim_newline() {
+        assert_eq!(trim_newline("token\n"), "token");
+        assert_eq!(trim_newline("token\r\n"), "token");
+        assert_eq!(trim_newline("token\n\n"), "token\n");
+        assert_eq!(trim_newline("token\r"), "token\r");
+        assert_eq!(trim_newline("token"), "token");
+    }
+
+    #[test]
+    fn test_eval_variable_value_file() {
+        let file = |name: &str, default: Option<Box<VariableValue>>| {
+            VariableValue::File(
+                File {
+                    space0: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    filename: Template {
+                        delimiter: None,
+                        elements: vec![TemplateElement::String {
+                            value: name.to_string(),
+                            encoded: name.to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 20)),
+                    },
+                    space1: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                },
+                default,
+            )
+        };
+        let mut variables = HashMap::default();
+        let current_dir = std::env::current_dir().unwrap();
+        let context_dir = ContextDir::new(current_dir.as_path(), Path::new(""));
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+
+        let value = file("tests/hello.txt", None);
+        assert_eq!(
+            eval_variable_value(&value, &mut variables, &context_dir, source_info).unwrap(),
+            Value::String("Hello World!".to_string())
+        );
+
+        // A missing file falls back to the default value.
+        let default = Some(Box::new(VariableValue::Null));
+        let value = file("tests/unknown.txt", default);
+        assert_eq!(
+            eval_variable_value(&value, &mut variables, &context_dir, source_info).unwrap(),
+            Value::Null
+        );
+
+        // A missing file without default is a runtime error.
+        let value = file("tests/unknown.txt", None);
+        let error = eval_variable_value(&value, &mut variables, &context_dir, source_info)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::FileReadAccess {
+                path: PathBuf::from("tests/unknown.txt")
+            }
+        );
+    }
+
+    #[test]
+    fn test_eval_variable_value_env() {
+        let mut variables = HashMap::default();
+        let context_dir = ContextDir::default();
+        let source_info = SourceInfo::new(Pos::new(1, 16), Pos::new(1, 16));
+
+        std::env::set_var("HURL_TEST_ENV_VARIABLE", "secret");
+        let value = VariableValue::Env("HURL_TEST_ENV_VARIABLE".to_string(), None);
+        assert_eq!(
+            eval_variable_value(&value, &mut variables, &context_dir, source_info).unwrap(),
+            Value::String("secret".to_string())
+        );
+
+        std::env::remove_var("HURL_TEST_ENV_VARIABLE_UNSET");
+        let value = VariableValue::Env("HURL_TEST_ENV_VARIABLE_UNSET".to_string(), None);
+        let error = eval_variable_value(&value, &mut variables, &context_dir, source_info)
+            .err()
+            .unwrap();
+        assert_eq!(error.source_info, source_info);
+        assert_eq!(
+            error.inner,
+            RunnerError::EnvVariableNotDefined {
+                name: "HURL_TEST_ENV_VARIABLE_UNSET".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_eval_variable_value_default() {
+        let mut variables = HashMap::default();
+        let current_dir = std::env::current_dir().unwrap();
+        let context_dir = ContextDir::new(current_dir.as_path(), Path::new(""));
+        let source_info = SourceInfo::new(Pos::new(1, 16), Pos::new(1, 16));
+        let default = Some(Box::new(VariableValue::Number(AstNumber::Integer(8080))));
+
+        std::env::remove_var("HURL_TEST_ENV_VARIABLE_DEFAULT");
+        let value = VariableValue::Env("HURL_TEST_ENV_VARIABLE_DEFAULT".to_string(), default);
+        assert_eq!(
+            eval_variable_value(&value, &mut variables, &context_dir, source_info).unwrap(),
+            Value::Number(Number::Integer(8080))
+        );
+
+        std::env::set_var("HURL_TEST_ENV_VARIABLE_DEFAULT", "80");
+        assert_eq!(
+            eval_variable_value(&value, &mut variables, &context_dir, source_info).unwrap(),
+            Value::String("80".to_string())
+        );
+    }
+
+    #[test]
+    fn test_eval_variable_value_big_integer() {
+        let mut variables = HashMap::default();
+        let context_dir = ContextDir::default();
+        let source_info = SourceInfo::new(Pos::new(1, 16), Pos::new(1, 16));
+
+        let value =
+            VariableValue::Number(AstNumber::BigInteger("12345678901234567890".to_string()));
+        let value = eval_variable_value(&value, &mut variables, &context_dir, source_info).unwrap();
+        assert_eq!(
+            value,
+            Value::Number(Number::BigInteger("12345678901234567890".to_string()))
+        );
+        assert_eq!(value.to_string(), "12345678901234567890");
+    }
+
+    #[test]
+    fn test_eval_retry_option() {
+        let option = parser::parse_entry_option("retry: {{retry}}").unwrap();
+        let OptionKind::Retry(retry_option) = option.kind else {
+            panic!("retry option expected");
+        };
+
+        let mut variables = HashMap::default();
+        for (count, expected) in [
+            (-1, Retry::Infinite),
+            (0, Retry::None),
+            (3, Retry::Finite(3)),
+        ] {
+            variables.insert("retry".to_string(), Value::Number(Number::Integer(count)));
+            assert_eq!(
+                eval_retry_option(&retry_option, &variables).unwrap(),
+                expected
+            );
+        }
+
+        variables.insert("retry".to_string(), Value::String("three".to_string()));
+        let error = eval_retry_option(&retry_option, &variables).err().unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::TemplateVariableInvalidType {
+                name: "retry".to_string(),
+                value: "three".to_string(),
+                expecting: "integer".to_string(),
+            }
+        );
+
+        variables.insert("retry".to_string(), Value::Number(Number::Integer(-2)));
+        let error = eval_retry_option(&retry_option, &variables).err().unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::TemplateVariableInvalidType {
+                name: "retry".to_string(),
+                value: "-2".to_string(),
+                expecting: "integer".to_string(),
+            }
+        );
     }
 
     #[test]
@@ -29,10 +29,14 @@ use crate::runner::{filter, Number, Value};
 pub type QueryResult = Result<Option<Value>, Error>;
 
 /// Evaluates this `query` and returns a [`QueryResult`], using the HTTP response `http_response` and `variables`.
+///
+/// If `raw` is true, the bytes queries (`bytes`, `sha256` and `md5`) are evaluated on the response
+/// body as received, without decompression.
 pub fn eval_query(
     query: &Query,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    raw: bool,
 ) -> QueryResult {
     match query.value.clone() {
         QueryValue::Status => eval_query_status(http_response),
@@ -54,9 +58,9 @@ pub fn eval_query(
         }
         QueryValue::Variable { name, .. } => eval_query_variable(&name, variables),
         QueryValue::Duration => eval_query_duration(http_response),
-        QueryValue::Bytes => eval_query_bytes(http_response, query.source_info),
-        QueryValue::Sha256 => eval_query_sha256(http_response, query.source_info),
-        QueryValue::Md5 => eval_query_md5(http_response, query.source_info),
+        QueryValue::Bytes => eval_query_bytes(http_response, raw, query.source_info),
+        QueryValue::Sha256 => eval_query_sha256(http_response, raw, query.source_info),
+        QueryValue::Md5 => eval_query_md5(http_response, raw, query.source_info),
         QueryValue::Certificate {
             attribute_name: field,
             ..
@@ -64,6 +68,16 @@ pub fn eval_query(
     }
 }
 
+/// Returns the body bytes of `response`, decompressed according to its `Content-Encoding` header
+/// unless `raw` is true.
+pub fn body_bytes(response: &http::Response, raw: bool) -> Result<Vec<u8>, http::HttpError> {
+    if raw {
+        Ok(response.body.clone())
+    } else {
+        response.uncompress_body()
+    }
+}
+
 fn eval_query_status(response: &http::Response) -> QueryResult {
     Ok(Some(Value::Number(Number::Integer(i64::from(
         response.status,
@@ -193,15 +207,23 @@ fn eval_query_duration(response: &http::Response) -> QueryResult {
     ))))
 }
 
-fn eval_query_bytes(response: &http::Response, query_source_info: SourceInfo) -> QueryResult {
-    match response.uncompress_body() {
+fn eval_query_bytes(
+    response: &http::Response,
+    raw: bool,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    match body_bytes(response, raw) {
         Ok(s) => Ok(Some(Value::Bytes(s))),
         Err(inner) => Err(Error::new(query_source_info, inner.into(), false)),
     }
 }
 
-fn eval_query_sha256(response: &http::Response, query_source_info: SourceInfo) -> QueryResult {
-    let bytes = match response.uncompress_body() {
+fn eval_query_sha256(
+    response: &http::Response,
+    raw: bool,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let bytes = match body_bytes(response, raw) {
         Ok(s) => s,
         Err(inner) => {
             return Err(Error::new(query_source_info, inner.into(), false));
@@ -214,8 +236,12 @@ fn eval_query_sha256(response: &http::Response, query_source_info: SourceInfo) -
     Ok(Some(bytes))
 }
 
-fn eval_query_md5(response: &http::Response, query_source_info: SourceInfo) -> QueryResult {
-    let bytes = match response.uncompress_body() {
+fn eval_query_md5(
+    response: &http::Response,
+    raw: bool,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let bytes = match body_bytes(response, raw) {
         Ok(s) => s,
         Err(inner) => {
             return Err(Error::new(query_source_info, inner.into(), false));
@@ -528,6 +554,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                false,
             )
             .unwrap()
             .unwrap(),
@@ -560,7 +587,13 @@ pub mod tests {
         //    assert_eq!(error.source_info.start, Pos { line: 1, column: 8 });
         //    assert_eq!(error.inner, RunnerError::QueryHeaderNotFound);
         assert_eq!(
-            eval_query(&query_header, &variables, &http::hello_http_response()).unwrap(),
+            eval_query(
+                &query_header,
+                &variables,
+                &http::hello_http_response(),
+                false
+            )
+            .unwrap(),
             None
         );
     }
@@ -587,9 +620,14 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query_header, &variables, &http::hello_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query_header,
+                &variables,
+                &http::hello_http_response(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
             Value::String(String::from("text/html; charset=utf-8"))
         );
     }
@@ -628,7 +666,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, false)
+                .unwrap()
+                .unwrap(),
             Value::String("DQAAAKEaem_vYg".to_string())
         );
 
@@ -655,7 +695,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, false)
+                .unwrap()
+                .unwrap(),
             Value::String("/accounts".to_string())
         );
 
@@ -682,7 +724,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, false)
+                .unwrap()
+                .unwrap(),
             Value::Unit
         );
 
@@ -708,7 +752,10 @@ pub mod tests {
                 },
             },
         };
-        assert_eq!(eval_query(&query, &variables, &response).unwrap(), None);
+        assert_eq!(
+            eval_query(&query, &variables, &response, false).unwrap(),
+            None
+        );
     }
 
     #[test]
@@ -804,6 +851,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                false,
             )
             .unwrap()
             .unwrap(),
@@ -816,6 +864,7 @@ pub mod tests {
             },
             &variables,
             &http::bytes_http_response(),
+            false,
         )
         .err()
         .unwrap();
@@ -838,7 +887,7 @@ pub mod tests {
             body: vec![200],
             ..Default::default()
         };
-        let error = eval_query(&xpath_users(), &variables, &http_response)
+        let error = eval_query(&xpath_users(), &variables, &http_response, false)
             .err()
             .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
@@ -871,9 +920,14 @@ pub mod tests {
                 },
             },
         };
-        let error = eval_query(&query, &variables, &http::xml_two_users_http_response())
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &query,
+            &variables,
+            &http::xml_two_users_http_response(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.inner, RunnerError::QueryInvalidXpathEval);
         assert_eq!(error.source_info.start, Pos { line: 1, column: 7 });
     }
@@ -887,6 +941,7 @@ pub mod tests {
                 &xpath_users(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                false,
             )
             .unwrap()
             .unwrap(),
@@ -897,6 +952,7 @@ pub mod tests {
                 &xpath_count_user_query(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                false,
             )
             .unwrap()
             .unwrap(),
@@ -935,6 +991,7 @@ pub mod tests {
                 &xpath_html_charset(),
                 &variables,
                 &http::html_http_response(),
+                false,
             )
             .unwrap()
             .unwrap(),
@@ -965,9 +1022,14 @@ pub mod tests {
             },
         };
 
-        let error = eval_query(&jsonpath_query, &variables, &http::json_http_response())
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &jsonpath_query,
+            &variables,
+            &http::json_http_response(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info.start,
             Pos {
@@ -990,7 +1052,7 @@ pub mod tests {
             body: String::into_bytes(String::from("xxx")),
             ..Default::default()
         };
-        let error = eval_query(&jsonpath_success(), &variables, &http_response)
+        let error = eval_query(&jsonpath_success(), &variables, &http_response, false)
             .err()
             .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
@@ -1006,7 +1068,7 @@ pub mod tests {
         };
         //assert_eq!(jsonpath_success().eval(http_response).unwrap(), Value::List(vec![]));
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http_response).unwrap(),
+            eval_query(&jsonpath_success(), &variables, &http_response, false).unwrap(),
             None
         );
     }
@@ -1015,15 +1077,25 @@ pub mod tests {
     fn test_query_json() {
         let variables = HashMap::new();
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http::json_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http::json_http_response(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
             Value::Bool(false)
         );
         assert_eq!(
-            eval_query(&jsonpath_errors(), &variables, &http::json_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &jsonpath_errors(),
+                &variables,
+                &http::json_http_response(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
             Value::List(vec![
                 Value::Object(vec![(
                     String::from("id"),
@@ -1041,15 +1113,25 @@ pub mod tests {
     fn test_query_regex() {
         let variables = HashMap::new();
         assert_eq!(
-            eval_query(&regex_name(), &variables, &http::hello_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &regex_name(),
+                &variables,
+                &http::hello_http_response(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
             Value::String("World".to_string())
         );
 
-        let error = eval_query(&regex_invalid(), &variables, &http::hello_http_response())
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &regex_invalid(),
+            &variables,
+            &http::hello_http_response(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info,
             SourceInfo::new(Pos::new(1, 7), Pos::new(1, 10))
@@ -1068,6 +1150,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                false,
             )
             .unwrap()
             .unwrap(),
@@ -1075,6 +1158,42 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_bytes_raw() {
+        let variables = HashMap::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Bytes,
+        };
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new("Content-Encoding", "gzip"));
+        let response = http::Response {
+            headers,
+            body: vec![
+                0x1f, 0x8b, 0x08, 0x08, 0xa7, 0x52, 0x85, 0x5f, 0x00, 0x03, 0x64, 0x61, 0x74, 0x61,
+                0x2e, 0x74, 0x78, 0x74, 0x00, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x08, 0xcf, 0x2f,
+                0xca, 0x49, 0x51, 0x04, 0x00, 0xa3, 0x1c, 0x29, 0x1c, 0x0c, 0x00, 0x00, 0x00,
+            ],
+            ..Default::default()
+        };
+
+        let Value::Bytes(bytes) = eval_query(&query, &variables, &response, true)
+            .unwrap()
+            .unwrap()
+        else {
+            panic!("expecting bytes")
+        };
+        assert!(bytes.starts_with(&[0x1f, 0x8b]));
+        assert_eq!(bytes, response.body);
+
+        assert_eq!(
+            eval_query(&query, &variables, &response, false)
+                .unwrap()
+                .unwrap(),
+            Value::Bytes(b"Hello World!".to_vec())
+        );
+    }
+
     #[test]
     fn test_query_sha256() {
         let variables = HashMap::new();
@@ -1088,7 +1207,8 @@ pub mod tests {
                 &http::Response {
                     body: vec![0xff],
                     ..Default::default()
-                }
+                },
+                false,
             )
             .unwrap()
             .unwrap(),
@@ -21,6 +21,7 @@ use hurl_core::ast::*;
 
 use crate::http;
 use crate::runner::error::{Error, RunnerError};
+use crate::runner::query::body_bytes;
 use crate::runner::result::{AssertResult, CaptureResult};
 use crate::runner::{assert, body, capture, json, multiline, template, Value};
 use crate::util::path::ContextDir;
@@ -60,6 +61,7 @@ pub fn eval_asserts(
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
     context_dir: &ContextDir,
+    raw: bool,
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
 
@@ -138,14 +140,14 @@ pub fn eval_asserts(
 
     // Second, evaluates implicit asserts on response body.
     if let Some(body) = &response.body {
-        let assert = eval_implicit_body_asserts(body, variables, http_response, context_dir);
+        let assert = eval_implicit_body_asserts(body, variables, http_response, context_dir, raw);
         asserts.push(assert);
     }
 
     // Then, checks all the explicit asserts.
     for assert in &response.asserts() {
         let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_response, context_dir);
+            assert::eval_explicit_assert(assert, variables, http_response, context_dir, raw);
         asserts.push(assert_result);
     }
     asserts
@@ -157,6 +159,7 @@ fn eval_implicit_body_asserts(
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
     context_dir: &ContextDir,
+    raw: bool,
 ) -> AssertResult {
     match &spec_body.value {
         Bytes::Json(value) => {
@@ -247,7 +250,7 @@ fn eval_implicit_body_asserts(
             ..
         }) => {
             let expected = Ok(Value::Bytes(value.to_vec()));
-            let actual = match http_response.uncompress_body() {
+            let actual = match body_bytes(http_response, raw) {
                 Ok(b) => Ok(Value::Bytes(b)),
                 Err(e) => {
                     let source_info = SourceInfo {
@@ -273,7 +276,7 @@ fn eval_implicit_body_asserts(
             ..
         }) => {
             let expected = Ok(Value::Bytes(value.to_vec()));
-            let actual = match http_response.uncompress_body() {
+            let actual = match body_bytes(http_response, raw) {
                 Ok(b) => Ok(Value::Bytes(b)),
                 Err(e) => {
                     let source_info = SourceInfo {
@@ -297,7 +300,7 @@ fn eval_implicit_body_asserts(
                 Ok(body) => Ok(Value::Bytes(body.bytes())),
                 Err(e) => Err(e),
             };
-            let actual = match http_response.uncompress_body() {
+            let actual = match body_bytes(http_response, raw) {
                 Ok(b) => Ok(Value::Bytes(b)),
                 Err(e) => {
                     let source_info = SourceInfo {
@@ -317,14 +320,16 @@ fn eval_implicit_body_asserts(
 }
 
 /// Evaluates captures from this HTTP `http_response`, given a set of `variables`.
+/// If `raw` is true, the response body is not decompressed.
 pub fn eval_captures(
     response: &Response,
     http_response: &http::Response,
     variables: &mut HashMap<String, Value>,
+    raw: bool,
 ) -> Result<Vec<CaptureResult>, Error> {
     let mut captures = vec![];
     for capture in &response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_response)?;
+        let capture_result = capture::eval_capture(capture, variables, http_response, raw)?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         variables.insert(capture_result.name.clone(), capture_result.value.clone());
@@ -395,6 +400,7 @@ mod tests {
                 &variables,
                 &http::xml_two_users_http_response(),
                 &context_dir,
+                false,
             ),
             vec![AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(2)))),
@@ -439,6 +445,7 @@ mod tests {
                 &user_response(),
                 &http::xml_two_users_http_response(),
                 &mut variables,
+                false,
             )
             .unwrap(),
             vec![CaptureResult {
@@ -29,6 +29,8 @@ use crate::util::term::Stdout;
 pub struct HurlResult {
     /// The entries result for this run.
     pub entries: Vec<EntryResult>,
+    /// Number of entries skipped during the run, with the `skip` option for instance.
+    pub skipped_entries: usize,
     /// Duration in milliseconds of the run.
     pub time_in_ms: u128,
     /// `true` if the run is successful, `false` if there has been runtime or asserts errors.
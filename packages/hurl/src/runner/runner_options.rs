@@ -17,48 +17,92 @@
  */
 use std::time::Duration;
 
-use hurl_core::ast::{Entry, Retry};
+use hurl_core::ast::{CertType, ContentLength, Entry, Retry, RetryTrigger, TlsVersion, TraceMode};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{Body, IpResolve, MultipartParam, Param, RequestedHttpVersion};
 use crate::runner::Output;
 use crate::util::path::ContextDir;
 
 pub struct RunnerOptionsBuilder {
+    abort_on_error: bool,
     aws_sigv4: Option<String>,
+    aws_region: Option<String>,
+    aws_service: Option<String>,
     cacert_file: Option<String>,
     client_cert_file: Option<String>,
+    client_cert_type: Option<CertType>,
     client_key_file: Option<String>,
+    client_key_type: Option<CertType>,
+    client_key_password: Option<String>,
     compressed: bool,
     connect_timeout: Duration,
+    connect_retries: Retry,
     connects_to: Vec<String>,
+    content_length: ContentLength,
     context_dir: ContextDir,
     continue_on_error: bool,
     cookie_input_file: Option<String>,
     delay: Duration,
+    dns_cache_ttl: Option<Duration>,
+    expect_100_timeout: Option<Duration>,
+    fail_with_body: bool,
     follow_location: bool,
     follow_location_trusted: bool,
+    fresh_connect: bool,
+    happy_eyeballs_timeout: Option<Duration>,
+    haproxy_protocol: bool,
     from_entry: Option<usize>,
     http_version: RequestedHttpVersion,
     ignore_asserts: bool,
     insecure: bool,
     ip_resolve: IpResolve,
+    limit_rate: Option<u64>,
     max_redirect: Option<usize>,
+    max_header_size: Option<u64>,
+    max_filesize: Option<u64>,
     netrc: bool,
     netrc_file: Option<String>,
     netrc_optional: bool,
+    no_buffer: bool,
     no_proxy: Option<String>,
     output: Option<Output>,
+    parallel: bool,
     path_as_is: bool,
+    post301: bool,
+    post302: bool,
+    post303: bool,
     post_entry: Option<fn() -> bool>,
     pre_entry: Option<fn(Entry) -> bool>,
     proxy: Option<String>,
+    proxy_user: Option<String>,
+    proxy_insecure: bool,
+    proxy_cacert_file: Option<String>,
+    range: Option<String>,
+    referer: Option<String>,
+    repeat: usize,
     resolves: Vec<String>,
     retry: Retry,
+    retry_deadline: Option<Duration>,
     retry_interval: Duration,
+    retry_jitter: u64,
+    retry_on: RetryTrigger,
+    retry_on_status: Vec<u32>,
+    send_fragment: bool,
     skip: bool,
+    ssh_public_key_file: Option<String>,
+    ssh_private_key_file: Option<String>,
     ssl_no_revoke: bool,
+    speed_limit: Option<u32>,
+    speed_time: Option<Duration>,
+    stop_on_failure: bool,
+    tcp_keepalive: bool,
+    tcp_keepidle: Option<Duration>,
+    tcp_keepintvl: Option<Duration>,
+    tcp_nodelay: bool,
     timeout: Duration,
+    tls_max: Option<TlsVersion>,
     to_entry: Option<usize>,
+    trace_body: Option<TraceMode>,
     unix_socket: Option<String>,
     user: Option<String>,
     user_agent: Option<String>,
@@ -67,41 +111,85 @@ pub struct RunnerOptionsBuilder {
 impl Default for RunnerOptionsBuilder {
     fn default() -> Self {
         RunnerOptionsBuilder {
+            abort_on_error: false,
             aws_sigv4: None,
+            aws_region: None,
+            aws_service: None,
             cacert_file: None,
             client_cert_file: None,
+            client_cert_type: None,
             client_key_file: None,
+            client_key_type: None,
+            client_key_password: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
+            connect_retries: Retry::None,
             connects_to: vec![],
+            content_length: ContentLength::Auto,
             context_dir: ContextDir::default(),
             continue_on_error: false,
             cookie_input_file: None,
             delay: Duration::from_millis(0),
+            dns_cache_ttl: None,
+            expect_100_timeout: None,
+            fail_with_body: false,
             follow_location: false,
             follow_location_trusted: false,
+            fresh_connect: false,
+            happy_eyeballs_timeout: None,
+            haproxy_protocol: false,
             from_entry: None,
             http_version: RequestedHttpVersion::default(),
             ignore_asserts: false,
             insecure: false,
             ip_resolve: IpResolve::default(),
+            limit_rate: None,
             max_redirect: Some(50),
+            max_header_size: None,
+            max_filesize: None,
             netrc: false,
             netrc_file: None,
             netrc_optional: false,
+            no_buffer: false,
             no_proxy: None,
             output: None,
+            parallel: false,
             path_as_is: false,
+            post301: false,
+            post302: false,
+            post303: false,
             post_entry: None,
             pre_entry: None,
             proxy: None,
+            proxy_user: None,
+            proxy_insecure: false,
+            proxy_cacert_file: None,
+            range: None,
+            referer: None,
+            repeat: 1,
             resolves: vec![],
             retry: Retry::None,
             retry_interval: Duration::from_millis(1000),
+            retry_jitter: 0,
+            retry_deadline: None,
+            retry_on: RetryTrigger::All,
+            retry_on_status: vec![],
+            send_fragment: false,
             skip: false,
+            ssh_public_key_file: None,
+            ssh_private_key_file: None,
             ssl_no_revoke: false,
+            speed_limit: None,
+            speed_time: None,
+            stop_on_failure: false,
+            tcp_keepalive: false,
+            tcp_keepidle: None,
+            tcp_keepintvl: None,
+            tcp_nodelay: true,
             timeout: Duration::from_secs(300),
+            tls_max: None,
             to_entry: None,
+            trace_body: None,
             unix_socket: None,
             user: None,
             user_agent: None,
@@ -121,6 +209,18 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Specifies the AWS SigV4 region, overriding the region of the AWS SigV4 option.
+    pub fn aws_region(&mut self, aws_region: Option<String>) -> &mut Self {
+        self.aws_region = aws_region;
+        self
+    }
+
+    /// Specifies the AWS SigV4 service, overriding the service of the AWS SigV4 option.
+    pub fn aws_service(&mut self, aws_service: Option<String>) -> &mut Self {
+        self.aws_service = aws_service;
+        self
+    }
+
     /// Specifies the certificate file for peer verification.
     /// The file may contain multiple CA certificates and must be in PEM format.
     pub fn cacert_file(&mut self, cacert_file: Option<String>) -> &mut Self {
@@ -134,12 +234,30 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the file type of the client certificate.
+    pub fn client_cert_type(&mut self, client_cert_type: Option<CertType>) -> &mut Self {
+        self.client_cert_type = client_cert_type;
+        self
+    }
+
     /// Sets private key file name.
     pub fn client_key_file(&mut self, client_key_file: Option<String>) -> &mut Self {
         self.client_key_file = client_key_file;
         self
     }
 
+    /// Sets the file type of the client private key.
+    pub fn client_key_type(&mut self, client_key_type: Option<CertType>) -> &mut Self {
+        self.client_key_type = client_key_type;
+        self
+    }
+
+    /// Sets the passphrase of the client private key.
+    pub fn client_key_password(&mut self, client_key_password: Option<String>) -> &mut Self {
+        self.client_key_password = client_key_password;
+        self
+    }
+
     /// Requests a compressed response using one of the algorithms br, gzip, deflate and
     /// automatically decompress the content.
     pub fn compressed(&mut self, compressed: bool) -> &mut Self {
@@ -155,6 +273,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of retries when the connection to the server fails.
+    ///
+    /// Default is no retry, -1 retries indefinitely.
+    pub fn connect_retries(&mut self, connect_retries: Retry) -> &mut Self {
+        self.connect_retries = connect_retries;
+        self
+    }
+
     /// Sets hosts mappings.
     ///
     /// Each value has the following format HOST1:PORT1:HOST2:PORT2
@@ -164,6 +290,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the `Content-Length` header behaviour.
+    ///
+    /// Default is `ContentLength::Auto`, the header is computed from the request body.
+    pub fn content_length(&mut self, content_length: ContentLength) -> &mut Self {
+        self.content_length = content_length;
+        self
+    }
+
     /// Sets delay (timeout) before the request.
     ///
     /// Default is 0 ms.
@@ -172,6 +306,20 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the time to keep DNS entries in memory.
+    ///
+    /// A duration of 0 disables the DNS cache, default is 60 s (libcurl default).
+    pub fn dns_cache_ttl(&mut self, dns_cache_ttl: Option<Duration>) -> &mut Self {
+        self.dns_cache_ttl = dns_cache_ttl;
+        self
+    }
+
+    /// Sets the maximum time to wait for a `100 Continue` response before sending the request body.
+    pub fn expect_100_timeout(&mut self, expect_100_timeout: Option<Duration>) -> &mut Self {
+        self.expect_100_timeout = expect_100_timeout;
+        self
+    }
+
     /// Sets root file system to import files in Hurl.
     ///
     /// This is used for both files in multipart form data and request body.
@@ -220,6 +368,28 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Forces a new connection for each request, closed after the transfer, instead of reusing
+    /// an existing one.
+    pub fn fresh_connect(&mut self, fresh_connect: bool) -> &mut Self {
+        self.fresh_connect = fresh_connect;
+        self
+    }
+
+    /// Sets the head start given to IPv6 over IPv4 when connecting to dual-stack hosts.
+    pub fn happy_eyeballs_timeout(
+        &mut self,
+        happy_eyeballs_timeout: Option<Duration>,
+    ) -> &mut Self {
+        self.happy_eyeballs_timeout = happy_eyeballs_timeout;
+        self
+    }
+
+    /// Sends a HAProxy PROXY protocol v1 header at the beginning of the connection.
+    pub fn haproxy_protocol(&mut self, haproxy_protocol: bool) -> &mut Self {
+        self.haproxy_protocol = haproxy_protocol;
+        self
+    }
+
     /// Executes Hurl file from `from_entry` (starting at 1), ignores the beginning of the file.
     pub fn from_entry(&mut self, from_entry: Option<usize>) -> &mut Self {
         self.from_entry = from_entry;
@@ -250,6 +420,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum transfer rate, in bytes per second, for both upload and download.
+    pub fn limit_rate(&mut self, limit_rate: Option<u64>) -> &mut Self {
+        self.limit_rate = limit_rate;
+        self
+    }
+
     /// Set maximum number of redirection-followings allowed
     ///
     /// By default, the limit is set to 50 redirections
@@ -258,12 +434,49 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum size in bytes of the response headers.
+    pub fn max_header_size(&mut self, max_header_size: Option<u64>) -> &mut Self {
+        self.max_header_size = max_header_size;
+        self
+    }
+
+    /// Sets the maximum size in bytes of the response body. The transfer fails if the body is larger.
+    pub fn max_filesize(&mut self, max_filesize: Option<u64>) -> &mut Self {
+        self.max_filesize = max_filesize;
+        self
+    }
+
+    /// Marks the entry as independent of its neighbours, so a parallel runner can run consecutive
+    /// parallel entries concurrently.
+    pub fn parallel(&mut self, parallel: bool) -> &mut Self {
+        self.parallel = parallel;
+        self
+    }
+
     /// Sets the path-as-is flag.
     pub fn path_as_is(&mut self, path_as_is: bool) -> &mut Self {
         self.path_as_is = path_as_is;
         self
     }
 
+    /// Keeps POST requests as POST when following a 301 redirect.
+    pub fn post301(&mut self, post301: bool) -> &mut Self {
+        self.post301 = post301;
+        self
+    }
+
+    /// Keeps POST requests as POST when following a 302 redirect.
+    pub fn post302(&mut self, post302: bool) -> &mut Self {
+        self.post302 = post302;
+        self
+    }
+
+    /// Keeps POST requests as POST when following a 303 redirect.
+    pub fn post303(&mut self, post303: bool) -> &mut Self {
+        self.post303 = post303;
+        self
+    }
+
     /// Sets the netrc flag.
     pub fn netrc(&mut self, netrc: bool) -> &mut Self {
         self.netrc = netrc;
@@ -282,6 +495,13 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Processes the response body as it's received: chunks are logged in verbose mode and, with
+    /// an `output` to standard output, written immediately.
+    pub fn no_buffer(&mut self, no_buffer: bool) -> &mut Self {
+        self.no_buffer = no_buffer;
+        self
+    }
+
     /// Sets list of hosts which do not use a proxy.
     pub fn no_proxy(&mut self, no_proxy: Option<String>) -> &mut Self {
         self.no_proxy = no_proxy;
@@ -316,6 +536,44 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the user and password (`user:password`) used to authenticate to the proxy.
+    pub fn proxy_user(&mut self, proxy_user: Option<String>) -> &mut Self {
+        self.proxy_user = proxy_user;
+        self
+    }
+
+    /// Allows insecure SSL connections to an HTTPS proxy.
+    pub fn proxy_insecure(&mut self, proxy_insecure: bool) -> &mut Self {
+        self.proxy_insecure = proxy_insecure;
+        self
+    }
+
+    /// Sets the CA certificate file used to verify an HTTPS proxy.
+    pub fn proxy_cacert_file(&mut self, proxy_cacert_file: Option<String>) -> &mut Self {
+        self.proxy_cacert_file = proxy_cacert_file;
+        self
+    }
+
+    /// Sets the byte range to request (ex: `0-1023`, `1024-`).
+    pub fn range(&mut self, range: Option<String>) -> &mut Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the `Referer` header of the request.
+    pub fn referer(&mut self, referer: Option<String>) -> &mut Self {
+        self.referer = referer;
+        self
+    }
+
+    /// Sets the number of times each entry is run in sequence.
+    ///
+    /// Default is 1, a value of 0 skips the entry.
+    pub fn repeat(&mut self, repeat: usize) -> &mut Self {
+        self.repeat = repeat;
+        self
+    }
+
     /// Provides a custom address for a specific host and port pair.
     pub fn resolves(&mut self, resolves: &[String]) -> &mut Self {
         self.resolves = resolves.to_vec();
@@ -338,11 +596,63 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum duration of all the attempts of an entry, retries and pauses included.
+    /// No retry is made once this deadline would be exceeded, even if retries remain.
+    ///
+    /// Default is no deadline.
+    pub fn retry_deadline(&mut self, retry_deadline: Option<Duration>) -> &mut Self {
+        self.retry_deadline = retry_deadline;
+        self
+    }
+
+    /// Sets the jitter of the retry interval, in percent (0 to 100): each retry interval is
+    /// randomly shortened or lengthened by up to this percentage.
+    ///
+    /// Default is 0 (no jitter).
+    pub fn retry_jitter(&mut self, retry_jitter: u64) -> &mut Self {
+        self.retry_jitter = retry_jitter;
+        self
+    }
+
+    /// Sets the errors that trigger a retry: assert failures, transport errors or both.
+    ///
+    /// Default is [`RetryTrigger::All`].
+    pub fn retry_on(&mut self, retry_on: RetryTrigger) -> &mut Self {
+        self.retry_on = retry_on;
+        self
+    }
+
+    /// Sets the list of HTTP status codes that trigger a retry.
+    ///
+    /// When empty (default), an entry is retried on any error.
+    pub fn retry_on_status(&mut self, retry_on_status: &[u32]) -> &mut Self {
+        self.retry_on_status = retry_on_status.to_vec();
+        self
+    }
+
+    /// Sends the URL fragment to the server as is, as part of the request target.
+    pub fn send_fragment(&mut self, send_fragment: bool) -> &mut Self {
+        self.send_fragment = send_fragment;
+        self
+    }
+
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
         self
     }
 
+    /// Sets the average transfer speed in bytes per second below which a transfer is considered too slow.
+    pub fn speed_limit(&mut self, speed_limit: Option<u32>) -> &mut Self {
+        self.speed_limit = speed_limit;
+        self
+    }
+
+    /// Sets the time during which the transfer speed can stay below the speed limit before the transfer is aborted.
+    pub fn speed_time(&mut self, speed_time: Option<Duration>) -> &mut Self {
+        self.speed_time = speed_time;
+        self
+    }
+
     /// Sets maximum time allowed for the transfer.
     ///
     /// Default 300 seconds.
@@ -351,12 +661,26 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum TLS version allowed for the connection.
+    pub fn tls_max(&mut self, tls_max: Option<TlsVersion>) -> &mut Self {
+        self.tls_max = tls_max;
+        self
+    }
+
     /// Executes Hurl file to `to_entry` (starting at 1), ignores the remaining of the file.
     pub fn to_entry(&mut self, to_entry: Option<usize>) -> &mut Self {
         self.to_entry = to_entry;
         self
     }
 
+    /// Sets how request and response bodies are logged in very verbose mode.
+    ///
+    /// Default is `None`, bodies are logged as text when possible, or as a slice of bytes.
+    pub fn trace_body(&mut self, trace_body: Option<TraceMode>) -> &mut Self {
+        self.trace_body = trace_body;
+        self
+    }
+
     /// Sets the specified unix domain socket to connect through, instead of using the network.
     pub fn unix_socket(&mut self, unix_socket: Option<String>) -> &mut Self {
         self.unix_socket = unix_socket;
@@ -376,44 +700,140 @@ impl RunnerOptionsBuilder {
     }
 
     /// Create an instance of [`RunnerOptions`].
+    /// Enables TCP keepalive probes.
+    pub fn tcp_keepalive(&mut self, tcp_keepalive: bool) -> &mut Self {
+        self.tcp_keepalive = tcp_keepalive;
+        self
+    }
+
+    /// Sets the delay the connection must be idle before sending TCP keepalive probes.
+    pub fn tcp_keepidle(&mut self, tcp_keepidle: Option<Duration>) -> &mut Self {
+        self.tcp_keepidle = tcp_keepidle;
+        self
+    }
+
+    /// Sets the interval between TCP keepalive probes.
+    pub fn tcp_keepintvl(&mut self, tcp_keepintvl: Option<Duration>) -> &mut Self {
+        self.tcp_keepintvl = tcp_keepintvl;
+        self
+    }
+
+    /// Sets the TCP_NODELAY option, disabling Nagle's algorithm.
+    ///
+    /// Default is true.
+    pub fn tcp_nodelay(&mut self, tcp_nodelay: bool) -> &mut Self {
+        self.tcp_nodelay = tcp_nodelay;
+        self
+    }
+
+    /// Sets the SSH public key file used to authenticate to `sftp://` and `scp://` URLs.
+    pub fn ssh_public_key_file(&mut self, ssh_public_key_file: Option<String>) -> &mut Self {
+        self.ssh_public_key_file = ssh_public_key_file;
+        self
+    }
+
+    /// Sets the SSH private key file used to authenticate to `sftp://` and `scp://` URLs.
+    pub fn ssh_private_key_file(&mut self, ssh_private_key_file: Option<String>) -> &mut Self {
+        self.ssh_private_key_file = ssh_private_key_file;
+        self
+    }
+
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
+            abort_on_error: self.abort_on_error,
+            accept: None,
+            accept_encoding: None,
             aws_sigv4: self.aws_sigv4.clone(),
+            aws_region: self.aws_region.clone(),
+            aws_service: self.aws_service.clone(),
+            body_file: None,
             cacert_file: self.cacert_file.clone(),
             client_cert_file: self.client_cert_file.clone(),
+            client_cert_type: self.client_cert_type,
             client_key_file: self.client_key_file.clone(),
+            client_key_type: self.client_key_type,
+            client_key_password: self.client_key_password.clone(),
             compressed: self.compressed,
             connect_timeout: self.connect_timeout,
+            connect_retries: self.connect_retries,
             connects_to: self.connects_to.clone(),
+            content_length: self.content_length,
+            content_type: None,
             delay: self.delay,
+            dns_cache_ttl: self.dns_cache_ttl,
+            expect_100_timeout: self.expect_100_timeout,
+            fail_with_body: self.fail_with_body,
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
+            crlf: false,
+            data_binary: None,
             follow_location: self.follow_location,
             follow_location_trusted: self.follow_location_trusted,
+            fresh_connect: self.fresh_connect,
+            globoff: false,
+            happy_eyeballs_timeout: self.happy_eyeballs_timeout,
+            haproxy_protocol: self.haproxy_protocol,
+            form: vec![],
             from_entry: self.from_entry,
             http_version: self.http_version,
             ignore_asserts: self.ignore_asserts,
             insecure: self.insecure,
             ip_resolve: self.ip_resolve,
+            json: None,
+            limit_rate: self.limit_rate,
+            login_options: None,
             max_redirect: self.max_redirect,
+            max_header_size: self.max_header_size,
+            max_filesize: self.max_filesize,
             netrc: self.netrc,
             netrc_file: self.netrc_file.clone(),
             netrc_optional: self.netrc_optional,
+            no_buffer: self.no_buffer,
             no_proxy: self.no_proxy.clone(),
             output: self.output.clone(),
+            parallel: self.parallel,
             path_as_is: self.path_as_is,
+            post301: self.post301,
+            post302: self.post302,
+            post303: self.post303,
             post_entry: self.post_entry,
             pre_entry: self.pre_entry,
             proxy: self.proxy.clone(),
+            proxy_user: self.proxy_user.clone(),
+            querystring: vec![],
+            proxy_insecure: self.proxy_insecure,
+            proxy_cacert_file: self.proxy_cacert_file.clone(),
+            range: self.range.clone(),
+            raw: false,
+            referer: self.referer.clone(),
+            repeat: self.repeat,
+            request_method: None,
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
+            retry_deadline: self.retry_deadline,
+            retry_jitter: self.retry_jitter,
+            retry_on: self.retry_on,
+            retry_on_status: self.retry_on_status.clone(),
+            send_fragment: self.send_fragment,
             skip: self.skip,
+            ssh_public_key_file: self.ssh_public_key_file.clone(),
+            ssh_private_key_file: self.ssh_private_key_file.clone(),
             ssl_no_revoke: self.ssl_no_revoke,
+            speed_limit: self.speed_limit,
+            speed_time: self.speed_time,
+            stop_on_failure: self.stop_on_failure,
+            tcp_keepalive: self.tcp_keepalive,
+            tcp_keepidle: self.tcp_keepidle,
+            tcp_keepintvl: self.tcp_keepintvl,
+            tcp_nodelay: self.tcp_nodelay,
             timeout: self.timeout,
+            tls_max: self.tls_max,
             to_entry: self.to_entry,
+            trace_body: self.trace_body,
             unix_socket: self.unix_socket.clone(),
+            url: None,
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
         }
@@ -422,42 +842,100 @@ impl RunnerOptionsBuilder {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RunnerOptions {
+    pub(crate) abort_on_error: bool,
+    pub(crate) accept: Option<String>,
+    pub(crate) accept_encoding: Option<String>,
     pub(crate) aws_sigv4: Option<String>,
+    pub(crate) aws_region: Option<String>,
+    pub(crate) aws_service: Option<String>,
+    pub(crate) body_file: Option<Body>,
     pub(crate) cacert_file: Option<String>,
     pub(crate) client_cert_file: Option<String>,
+    pub(crate) client_cert_type: Option<CertType>,
     pub(crate) client_key_file: Option<String>,
+    pub(crate) client_key_type: Option<CertType>,
+    pub(crate) client_key_password: Option<String>,
     pub(crate) compressed: bool,
     pub(crate) connect_timeout: Duration,
+    pub(crate) connect_retries: Retry,
     pub(crate) connects_to: Vec<String>,
+    pub(crate) content_length: ContentLength,
+    pub(crate) content_type: Option<String>,
     pub(crate) delay: Duration,
+    pub(crate) dns_cache_ttl: Option<Duration>,
+    pub(crate) expect_100_timeout: Option<Duration>,
+    pub(crate) fail_with_body: bool,
     pub(crate) context_dir: ContextDir,
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
+    pub(crate) crlf: bool,
+    pub(crate) data_binary: Option<Body>,
     pub(crate) follow_location: bool,
+    pub(crate) form: Vec<MultipartParam>,
     pub(crate) follow_location_trusted: bool,
+    pub(crate) fresh_connect: bool,
+    pub(crate) globoff: bool,
+    pub(crate) happy_eyeballs_timeout: Option<Duration>,
+    pub(crate) haproxy_protocol: bool,
     pub(crate) from_entry: Option<usize>,
     pub(crate) http_version: RequestedHttpVersion,
     pub(crate) ignore_asserts: bool,
     pub(crate) ip_resolve: IpResolve,
+    pub(crate) json: Option<String>,
+    pub(crate) limit_rate: Option<u64>,
+    pub(crate) login_options: Option<String>,
     pub(crate) insecure: bool,
     pub(crate) max_redirect: Option<usize>,
+    pub(crate) max_header_size: Option<u64>,
+    pub(crate) max_filesize: Option<u64>,
     pub(crate) netrc: bool,
     pub(crate) netrc_file: Option<String>,
     pub(crate) netrc_optional: bool,
+    pub(crate) no_buffer: bool,
     pub(crate) no_proxy: Option<String>,
     pub(crate) output: Option<Output>,
+    pub(crate) parallel: bool,
     pub(crate) path_as_is: bool,
+    pub(crate) post301: bool,
+    pub(crate) post302: bool,
+    pub(crate) post303: bool,
     pub(crate) post_entry: Option<fn() -> bool>,
     pub(crate) pre_entry: Option<fn(Entry) -> bool>,
     pub(crate) proxy: Option<String>,
+    pub(crate) proxy_user: Option<String>,
+    pub(crate) querystring: Vec<Param>,
+    pub(crate) proxy_insecure: bool,
+    pub(crate) proxy_cacert_file: Option<String>,
+    pub(crate) range: Option<String>,
+    pub(crate) raw: bool,
+    pub(crate) referer: Option<String>,
+    pub(crate) repeat: usize,
+    pub(crate) request_method: Option<String>,
     pub(crate) resolves: Vec<String>,
     pub(crate) retry: Retry,
+    pub(crate) retry_deadline: Option<Duration>,
     pub(crate) retry_interval: Duration,
+    pub(crate) retry_jitter: u64,
+    pub(crate) retry_on: RetryTrigger,
+    pub(crate) retry_on_status: Vec<u32>,
+    pub(crate) send_fragment: bool,
     pub(crate) skip: bool,
+    pub(crate) ssh_public_key_file: Option<String>,
+    pub(crate) ssh_private_key_file: Option<String>,
     pub(crate) ssl_no_revoke: bool,
+    pub(crate) speed_limit: Option<u32>,
+    pub(crate) speed_time: Option<Duration>,
+    pub(crate) stop_on_failure: bool,
+    pub(crate) tcp_keepalive: bool,
+    pub(crate) tcp_keepidle: Option<Duration>,
+    pub(crate) tcp_keepintvl: Option<Duration>,
+    pub(crate) tcp_nodelay: bool,
     pub(crate) timeout: Duration,
+    pub(crate) tls_max: Option<TlsVersion>,
     pub(crate) to_entry: Option<usize>,
+    pub(crate) trace_body: Option<TraceMode>,
     pub(crate) unix_socket: Option<String>,
+    pub(crate) url: Option<String>,
     pub(crate) user: Option<String>,
     pub(crate) user_agent: Option<String>,
 }
@@ -467,3 +945,91 @@ impl Default for RunnerOptions {
         RunnerOptionsBuilder::default().build()
     }
 }
+
+impl RunnerOptions {
+    /// Returns the pause before a retry: the retry interval, with its random jitter.
+    pub(crate) fn retry_delay(&self) -> Duration {
+        if self.retry_jitter == 0 {
+            return self.retry_interval;
+        }
+        // The 48 most significant bits of a v4 UUID are random.
+        let random = (uuid::Uuid::new_v4().as_u128() >> 80) as f64 / (1u64 << 48) as f64;
+        jitter(self.retry_interval, self.retry_jitter, random)
+    }
+
+    /// Returns `true` if the response body is written to standard output as it's received.
+    ///
+    /// Compressed bodies are only written once fully received, to be decompressed. Bodies of
+    /// entries that can be retried are also written once the entry has succeeded, so that each
+    /// attempt doesn't output its body again.
+    pub(crate) fn streams_output(&self) -> bool {
+        self.no_buffer
+            && !self.compressed
+            && matches!(self.output, Some(Output::Stdout))
+            && self.retry == Retry::None
+            && self.connect_retries == Retry::None
+    }
+}
+
+/// Shifts `interval` by up to `percent` percent, `random` in `[0, 1)` giving the shift from
+/// `-percent` to `+percent`.
+fn jitter(interval: Duration, percent: u64, random: f64) -> Duration {
+    let shift = (2.0 * random - 1.0) * percent as f64 / 100.0;
+    interval.mul_f64(1.0 + shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streams_output() {
+        let options = RunnerOptionsBuilder::new()
+            .no_buffer(true)
+            .output(Some(Output::Stdout))
+            .build();
+        assert!(options.streams_output());
+
+        let options = RunnerOptionsBuilder::new()
+            .no_buffer(true)
+            .output(Some(Output::Stdout))
+            .retry(Retry::Finite(3))
+            .build();
+        assert!(!options.streams_output());
+
+        let options = RunnerOptionsBuilder::new()
+            .no_buffer(true)
+            .output(Some(Output::Stdout))
+            .connect_retries(Retry::Infinite)
+            .build();
+        assert!(!options.streams_output());
+    }
+
+    #[test]
+    fn test_jitter() {
+        let interval = Duration::from_millis(1000);
+        assert_eq!(jitter(interval, 0, 0.0), interval);
+        assert_eq!(jitter(interval, 20, 0.0), Duration::from_millis(800));
+        assert_eq!(jitter(interval, 20, 0.5), interval);
+        assert_eq!(jitter(interval, 20, 0.75), Duration::from_millis(1100));
+        assert_eq!(jitter(interval, 100, 0.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let options = RunnerOptionsBuilder::new()
+            .retry_interval(Duration::from_millis(1000))
+            .build();
+        assert_eq!(options.retry_delay(), Duration::from_millis(1000));
+
+        let options = RunnerOptionsBuilder::new()
+            .retry_interval(Duration::from_millis(1000))
+            .retry_jitter(10)
+            .build();
+        for _ in 0..100 {
+            let delay = options.retry_delay();
+            assert!(delay >= Duration::from_millis(900));
+            assert!(delay <= Duration::from_millis(1100));
+        }
+    }
+}
@@ -57,6 +57,14 @@ impl Stdout {
         }
     }
 
+    /// Flushes the standard output (only in [`WriteMode::Immediate`] mode).
+    pub fn flush(&mut self) -> Result<(), io::Error> {
+        match self.mode {
+            WriteMode::Immediate => io::stdout().flush(),
+            WriteMode::Buffered => Ok(()),
+        }
+    }
+
     /// Returns the buffered standard output.
     pub fn buffer(&self) -> &[u8] {
         &self.buffer
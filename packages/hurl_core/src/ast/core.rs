@@ -711,115 +711,349 @@ pub struct EntryOption {
     pub line_terminator0: LineTerminator,
 }
 
+impl EntryOption {
+    /// Returns the trailing comment of this option (ex: `insecure: true # only in dev`), if any.
+    pub fn comment(&self) -> Option<&Comment> {
+        self.line_terminator0.comment.as_ref()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OptionKind {
+    AbortOnError(BooleanOption),
+    Accept(Template),
+    AcceptEncoding(Template),
+    AwsRegion(Template),
+    AwsService(Template),
     AwsSigV4(Template),
+    BodyFile(Template),
     CaCertificate(Template),
+    CertType(CertType),
     ClientCert(Template),
     ClientKey(Template),
     Compressed(BooleanOption),
+    ConnectRetries(RetryOption),
+    ConnectTimeout(DurationOption),
     ConnectTo(Template),
-    Delay(NaturalOption),
+    ContentLength(ContentLength),
+    ContentType(Template),
+    ContinueOnError(BooleanOption),
+    Crlf(BooleanOption),
+    DataBinary(Template),
+    Delay(DurationOption),
+    DnsCacheTtl(NaturalOption),
+    Expect100Timeout(NaturalOption),
+    FailWithBody(BooleanOption),
+    FollowLocation(BooleanOption),
+    FollowLocationTrusted(BooleanOption),
+    Form(Template),
+    FreshConnect(BooleanOption),
+    GlobOff(BooleanOption),
+    HappyEyeballsTimeout(NaturalOption),
+    HaproxyProtocol(BooleanOption),
     Http10(BooleanOption),
     Http11(BooleanOption),
     Http2(BooleanOption),
     Http3(BooleanOption),
+    HttpVersion(HttpVersion),
     Insecure(BooleanOption),
     IpV4(BooleanOption),
     IpV6(BooleanOption),
-    FollowLocation(BooleanOption),
-    FollowLocationTrusted(BooleanOption),
+    IpVersion(IpVersion),
+    Json(Template),
+    KeyPassword(Template),
+    KeyType(CertType),
+    LimitRate(ByteSize),
+    LocalVariable(VariableDefinition),
+    LoginOptions(Template),
+    MaxFileSize(NaturalOption),
+    MaxHeaderSize(ByteSize),
     MaxRedirect(NaturalOption),
+    MaxTime(DurationOption),
     NetRc(BooleanOption),
     NetRcFile(Template),
     NetRcOptional(BooleanOption),
+    NoBuffer(BooleanOption),
+    NoProxy(Template),
     Output(Template),
+    Parallel(BooleanOption),
     PathAsIs(BooleanOption),
+    Post301(BooleanOption),
+    Post302(BooleanOption),
+    Post303(BooleanOption),
     Proxy(Template),
+    ProxyCaCert(Template),
+    ProxyInsecure(BooleanOption),
+    ProxyUser(Template),
+    Query(Template),
+    Range(Template),
+    RawResponse(BooleanOption),
+    Referer(Template),
+    /// Removal of a previous `resolve` entry: `-HOST:PORT`.
+    RemoveResolve(Template),
+    Repeat(NaturalOption),
+    RequestMethod(Template),
     Resolve(Template),
     Retry(RetryOption),
-    RetryInterval(NaturalOption),
+    /// Maximum duration of all the attempts of an entry, in milliseconds.
+    RetryDeadline(DurationOption),
+    RetryInterval(DurationOption),
+    /// Jitter of the retry interval, in percent (0 to 100).
+    RetryJitter(NaturalOption),
+    RetryOn(RetryTrigger),
+    RetryOnStatus(Vec<u32>),
+    SendFragment(BooleanOption),
     Skip(BooleanOption),
+    SpeedLimit(NaturalOption),
+    SpeedTime(NaturalOption),
+    SshPrivateKey(Template),
+    SshPublicKey(Template),
+    StopOnFailure(BooleanOption),
+    TcpKeepAlive(BooleanOption),
+    TcpKeepIdle(NaturalOption),
+    TcpKeepIntvl(NaturalOption),
+    TcpNoDelay(BooleanOption),
+    TlsMax(TlsVersion),
+    TraceBody(TraceMode),
     UnixSocket(Template),
+    Url(Template),
     User(Template),
+    UserAgent(Template),
     Variable(VariableDefinition),
     Verbose(BooleanOption),
+    /// Verbosity level: `0` (not verbose), `1` (verbose) or `2` (very verbose).
+    VerboseLevel(u8),
     VeryVerbose(BooleanOption),
 }
 
 impl OptionKind {
     pub fn name(&self) -> &'static str {
         match self {
+            OptionKind::AbortOnError(_) => "abort-on-error",
+            OptionKind::Accept(_) => "accept",
+            OptionKind::AcceptEncoding(_) => "accept-encoding",
+            OptionKind::AwsRegion(_) => "aws-region",
+            OptionKind::AwsService(_) => "aws-service",
             OptionKind::AwsSigV4(_) => "aws-sigv4",
+            OptionKind::BodyFile(_) => "body-file",
             OptionKind::CaCertificate(_) => "cacert",
+            OptionKind::CertType(_) => "cert-type",
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
             OptionKind::Compressed(_) => "compressed",
+            OptionKind::ConnectRetries(_) => "connect-retries",
+            OptionKind::ConnectTimeout(_) => "connect-timeout",
             OptionKind::ConnectTo(_) => "connect-to",
+            OptionKind::ContentLength(_) => "content-length",
+            OptionKind::ContentType(_) => "content-type",
+            OptionKind::ContinueOnError(_) => "continue-on-error",
+            OptionKind::Crlf(_) => "crlf",
+            OptionKind::DataBinary(_) => "data-binary",
             OptionKind::Delay(_) => "delay",
+            OptionKind::DnsCacheTtl(_) => "dns-cache-ttl",
+            OptionKind::Expect100Timeout(_) => "expect-100-timeout",
+            OptionKind::FailWithBody(_) => "fail-with-body",
             OptionKind::FollowLocation(_) => "location",
             OptionKind::FollowLocationTrusted(_) => "location-trusted",
+            OptionKind::Form(_) => "form",
+            OptionKind::FreshConnect(_) => "fresh-connect",
+            OptionKind::GlobOff(_) => "globoff",
+            OptionKind::HappyEyeballsTimeout(_) => "happy-eyeballs-timeout",
+            OptionKind::HaproxyProtocol(_) => "haproxy-protocol",
             OptionKind::Http10(_) => "http1.0",
             OptionKind::Http11(_) => "http1.1",
             OptionKind::Http2(_) => "http2",
             OptionKind::Http3(_) => "http3",
+            OptionKind::HttpVersion(_) => "http-version",
             OptionKind::Insecure(_) => "insecure",
             OptionKind::IpV4(_) => "ipv4",
             OptionKind::IpV6(_) => "ipv6",
+            OptionKind::IpVersion(_) => "ip-version",
+            OptionKind::Json(_) => "json",
+            OptionKind::KeyPassword(_) => "key-password",
+            OptionKind::KeyType(_) => "key-type",
+            OptionKind::LimitRate(_) => "limit-rate",
+            OptionKind::LocalVariable(_) => "variable-local",
+            OptionKind::LoginOptions(_) => "login-options",
+            OptionKind::MaxFileSize(_) => "max-filesize",
+            OptionKind::MaxHeaderSize(_) => "max-header-size",
             OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::MaxTime(_) => "max-time",
             OptionKind::NetRc(_) => "netrc",
             OptionKind::NetRcFile(_) => "netrc-file",
             OptionKind::NetRcOptional(_) => "netrc-optional",
+            OptionKind::NoBuffer(_) => "no-buffer",
+            OptionKind::NoProxy(_) => "noproxy",
             OptionKind::Output(_) => "output",
+            OptionKind::Parallel(_) => "parallel",
             OptionKind::PathAsIs(_) => "path-as-is",
+            OptionKind::Post301(_) => "post301",
+            OptionKind::Post302(_) => "post302",
+            OptionKind::Post303(_) => "post303",
             OptionKind::Proxy(_) => "proxy",
+            OptionKind::ProxyCaCert(_) => "proxy-cacert",
+            OptionKind::ProxyInsecure(_) => "proxy-insecure",
+            OptionKind::ProxyUser(_) => "proxy-user",
+            OptionKind::Query(_) => "query",
+            OptionKind::Range(_) => "range",
+            OptionKind::RawResponse(_) => "raw",
+            OptionKind::Referer(_) => "referer",
+            OptionKind::RemoveResolve(_) => "resolve",
+            OptionKind::Repeat(_) => "repeat",
+            OptionKind::RequestMethod(_) => "request",
             OptionKind::Resolve(_) => "resolve",
             OptionKind::Retry(_) => "retry",
+            OptionKind::RetryDeadline(_) => "retry-deadline",
             OptionKind::RetryInterval(_) => "retry-interval",
+            OptionKind::RetryJitter(_) => "retry-jitter",
+            OptionKind::RetryOn(_) => "retry-on",
+            OptionKind::RetryOnStatus(_) => "retry-on-status",
+            OptionKind::SendFragment(_) => "send-fragment",
             OptionKind::Skip(_) => "skip",
+            OptionKind::SpeedLimit(_) => "speed-limit",
+            OptionKind::SpeedTime(_) => "speed-time",
+            OptionKind::SshPrivateKey(_) => "ssh-private-key",
+            OptionKind::SshPublicKey(_) => "ssh-public-key",
+            OptionKind::StopOnFailure(_) => "stop-on-failure",
+            OptionKind::TcpKeepAlive(_) => "tcp-keepalive",
+            OptionKind::TcpKeepIdle(_) => "tcp-keepidle",
+            OptionKind::TcpKeepIntvl(_) => "tcp-keepintvl",
+            OptionKind::TcpNoDelay(_) => "tcp-nodelay",
+            OptionKind::TlsMax(_) => "tls-max",
+            OptionKind::TraceBody(_) => "trace-body",
             OptionKind::UnixSocket(_) => "unix-socket",
+            OptionKind::Url(_) => "url",
             OptionKind::User(_) => "user",
+            OptionKind::UserAgent(_) => "user-agent",
             OptionKind::Variable(_) => "variable",
             OptionKind::Verbose(_) => "verbose",
+            OptionKind::VerboseLevel(_) => "verbose",
             OptionKind::VeryVerbose(_) => "very-verbose",
         }
     }
 
+    /// Returns `true` if the value of this option may contain a secret (credentials,
+    /// passphrase...) and must not be rendered in logs.
+    pub fn is_secret(&self) -> bool {
+        OptionKind::is_secret_name(self.name())
+    }
+
+    /// Returns `true` if the value of the option `name` may contain a secret.
+    ///
+    /// This is the only list of secret options, shared by option kinds and option specs.
+    pub fn is_secret_name(name: &str) -> bool {
+        matches!(name, "key-password" | "proxy-user" | "user")
+    }
+
     pub fn value_as_str(&self) -> String {
         match self {
+            OptionKind::AbortOnError(value) => value.to_string(),
+            OptionKind::Accept(value) => value.to_string(),
+            OptionKind::AcceptEncoding(value) => value.to_string(),
+            OptionKind::AwsRegion(value) => value.to_string(),
+            OptionKind::AwsService(value) => value.to_string(),
             OptionKind::AwsSigV4(value) => value.to_string(),
+            OptionKind::BodyFile(filename) => filename.to_string(),
             OptionKind::CaCertificate(filename) => filename.to_string(),
+            OptionKind::CertType(value) => value.to_string(),
             OptionKind::ClientCert(filename) => filename.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
+            OptionKind::ConnectRetries(value) => value.to_string(),
+            OptionKind::ConnectTimeout(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
+            OptionKind::ContentLength(value) => value.to_string(),
+            OptionKind::ContentType(value) => value.to_string(),
+            OptionKind::ContinueOnError(value) => value.to_string(),
+            OptionKind::Crlf(value) => value.to_string(),
+            OptionKind::DataBinary(filename) => format!("@{filename}"),
             OptionKind::Delay(value) => value.to_string(),
+            OptionKind::DnsCacheTtl(value) => value.to_string(),
+            OptionKind::Expect100Timeout(value) => value.to_string(),
+            OptionKind::FailWithBody(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
             OptionKind::FollowLocationTrusted(value) => value.to_string(),
+            OptionKind::Form(value) => value.to_string(),
+            OptionKind::FreshConnect(value) => value.to_string(),
+            OptionKind::GlobOff(value) => value.to_string(),
+            OptionKind::HappyEyeballsTimeout(value) => value.to_string(),
+            OptionKind::HaproxyProtocol(value) => value.to_string(),
             OptionKind::Http10(value) => value.to_string(),
             OptionKind::Http11(value) => value.to_string(),
             OptionKind::Http2(value) => value.to_string(),
             OptionKind::Http3(value) => value.to_string(),
+            OptionKind::HttpVersion(value) => value.to_string(),
             OptionKind::Insecure(value) => value.to_string(),
             OptionKind::IpV4(value) => value.to_string(),
             OptionKind::IpV6(value) => value.to_string(),
+            OptionKind::IpVersion(value) => value.to_string(),
+            OptionKind::Json(value) => value.to_string(),
+            OptionKind::KeyPassword(value) => value.to_string(),
+            OptionKind::KeyType(value) => value.to_string(),
+            OptionKind::LimitRate(value) => value.to_string(),
+            OptionKind::LocalVariable(VariableDefinition { name, value, .. }) => {
+                format!("{name}={value}")
+            }
+            OptionKind::LoginOptions(value) => value.to_string(),
+            OptionKind::MaxFileSize(value) => value.to_string(),
+            OptionKind::MaxHeaderSize(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::MaxTime(value) => value.to_string(),
             OptionKind::NetRc(value) => value.to_string(),
             OptionKind::NetRcFile(filename) => filename.to_string(),
             OptionKind::NetRcOptional(value) => value.to_string(),
+            OptionKind::NoBuffer(value) => value.to_string(),
+            OptionKind::NoProxy(value) => value.to_string(),
             OptionKind::Output(filename) => filename.to_string(),
+            OptionKind::Parallel(value) => value.to_string(),
             OptionKind::PathAsIs(value) => value.to_string(),
+            OptionKind::Post301(value) => value.to_string(),
+            OptionKind::Post302(value) => value.to_string(),
+            OptionKind::Post303(value) => value.to_string(),
             OptionKind::Proxy(value) => value.to_string(),
+            OptionKind::ProxyCaCert(filename) => filename.to_string(),
+            OptionKind::ProxyInsecure(value) => value.to_string(),
+            OptionKind::ProxyUser(value) => value.to_string(),
+            OptionKind::Query(value) => value.to_string(),
+            OptionKind::Range(value) => value.to_string(),
+            OptionKind::RawResponse(value) => value.to_string(),
+            OptionKind::Referer(value) => value.to_string(),
+            OptionKind::RemoveResolve(value) => format!("-{value}"),
+            OptionKind::Repeat(value) => value.to_string(),
+            OptionKind::RequestMethod(value) => value.to_string(),
             OptionKind::Resolve(value) => value.to_string(),
             OptionKind::Retry(value) => value.to_string(),
+            OptionKind::RetryDeadline(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
+            OptionKind::RetryJitter(value) => value.to_string(),
+            OptionKind::RetryOn(value) => value.to_string(),
+            OptionKind::RetryOnStatus(value) => value
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            OptionKind::SendFragment(value) => value.to_string(),
             OptionKind::Skip(value) => value.to_string(),
+            OptionKind::SpeedLimit(value) => value.to_string(),
+            OptionKind::SpeedTime(value) => value.to_string(),
+            OptionKind::SshPrivateKey(filename) => filename.to_string(),
+            OptionKind::SshPublicKey(filename) => filename.to_string(),
+            OptionKind::StopOnFailure(value) => value.to_string(),
+            OptionKind::TcpKeepAlive(value) => value.to_string(),
+            OptionKind::TcpKeepIdle(value) => value.to_string(),
+            OptionKind::TcpKeepIntvl(value) => value.to_string(),
+            OptionKind::TcpNoDelay(value) => value.to_string(),
+            OptionKind::TlsMax(value) => value.to_string(),
+            OptionKind::TraceBody(value) => value.to_string(),
             OptionKind::UnixSocket(value) => value.to_string(),
+            OptionKind::Url(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
+            OptionKind::UserAgent(value) => value.to_string(),
             OptionKind::Variable(VariableDefinition { name, value, .. }) => {
                 format!("{name}={value}")
             }
             OptionKind::Verbose(value) => value.to_string(),
+            OptionKind::VerboseLevel(value) => value.to_string(),
             OptionKind::VeryVerbose(value) => value.to_string(),
         }
     }
@@ -837,6 +1071,39 @@ pub enum NaturalOption {
     Expression(Expr),
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DurationOption {
+    Literal(Duration),
+    Expression(Expr),
+}
+
+/// A duration, like `2s` or `500`, normalized to milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Duration {
+    /// Value of the duration, in milliseconds.
+    pub millis: u64,
+    /// Unit of the duration as written in the source, `None` for a bare number of milliseconds.
+    pub unit: Option<DurationUnit>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationUnit {
+    MilliSecond,
+    Second,
+    Minute,
+}
+
+impl DurationUnit {
+    /// Returns the number of milliseconds in one unit.
+    pub fn millis(&self) -> u64 {
+        match self {
+            DurationUnit::MilliSecond => 1,
+            DurationUnit::Second => 1_000,
+            DurationUnit::Minute => 60_000,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RetryOption {
     Literal(Retry),
@@ -857,6 +1124,12 @@ pub enum VariableValue {
     Bool(bool),
     Number(Number),
     String(Template),
+    List(Vec<VariableValue>),
+    Object(Vec<(String, VariableValue)>),
+    /// A value read from a file, with an optional default if the file can't be read.
+    File(File, Option<Box<VariableValue>>),
+    /// A value read from an environment variable, with an optional default if it's not set.
+    Env(String, Option<Box<VariableValue>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -921,3 +1194,121 @@ pub enum Retry {
     Finite(usize),
     Infinite,
 }
+
+/// A size in bytes, with an optional unit (ex: `100K`, `1Mbps`).
+///
+/// Byte units (`K`, `M`, `G`) are multiples of 1024 bytes, bit units (`Kbps`, `Mbps`, `Gbps`)
+/// are multiples of 1000 bits.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct ByteSize {
+    pub value: u64,
+    pub unit: Option<ByteUnit>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum ByteUnit {
+    Kilo,
+    Mega,
+    Giga,
+    KiloBit,
+    MegaBit,
+    GigaBit,
+}
+
+impl ByteSize {
+    /// Returns this size converted to bytes.
+    pub fn bytes(&self) -> u64 {
+        match self.unit {
+            None => self.value,
+            Some(ByteUnit::Kilo) => self.value.saturating_mul(1024),
+            Some(ByteUnit::Mega) => self.value.saturating_mul(1024 * 1024),
+            Some(ByteUnit::Giga) => self.value.saturating_mul(1024 * 1024 * 1024),
+            Some(ByteUnit::KiloBit) => self.value.saturating_mul(1_000) / 8,
+            Some(ByteUnit::MegaBit) => self.value.saturating_mul(1_000_000) / 8,
+            Some(ByteUnit::GigaBit) => self.value.saturating_mul(1_000_000_000) / 8,
+        }
+    }
+}
+
+/// The `Content-Length` header behaviour of a request.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum ContentLength {
+    /// Computed from the request body.
+    Auto,
+    /// Not sent.
+    Omit,
+    /// Sent with this value, regardless of the request body.
+    Fixed(u64),
+}
+
+/// The file type of a `cert-type` or `key-type` option.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum CertType {
+    /// PEM encoded file.
+    Pem,
+    /// DER encoded file.
+    Der,
+    /// PKCS#12 encoded file.
+    P12,
+    /// Certificate or key provided by an OpenSSL engine.
+    Eng,
+}
+
+/// The HTTP version of an `http-version` option.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum HttpVersion {
+    /// HTTP/1.0
+    Http10,
+    /// HTTP/1.1
+    Http11,
+    /// HTTP/2
+    Http2,
+    /// HTTP/3
+    Http3,
+}
+
+/// The IP version of an `ip-version` option.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum IpVersion {
+    /// Addresses of all IP versions are used.
+    Auto,
+    /// Only IPv4 addresses are used.
+    V4,
+    /// Only IPv6 addresses are used.
+    V6,
+}
+
+/// The TLS version of a `tls-max` option.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum TlsVersion {
+    /// TLS 1.0
+    Tls10,
+    /// TLS 1.1
+    Tls11,
+    /// TLS 1.2
+    Tls12,
+    /// TLS 1.3
+    Tls13,
+}
+
+/// Errors triggering the retry of an entry.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum RetryTrigger {
+    /// Retry on assert failures only.
+    Asserts,
+    /// Retry on transport errors only (connection failure, timeout...).
+    Transport,
+    /// Retry on any error.
+    All,
+}
+
+/// How request and response bodies are logged in very verbose mode.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum TraceMode {
+    /// Bodies are not logged.
+    Off,
+    /// Bodies are logged as ASCII, non printable bytes being replaced by `.`.
+    Ascii,
+    /// Bodies are logged as hexadecimal bytes.
+    Hex,
+}
@@ -187,6 +187,35 @@ impl fmt::Display for NaturalOption {
     }
 }
 
+impl fmt::Display for DurationOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationOption::Literal(v) => write!(f, "{}", v),
+            DurationOption::Expression(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Some(unit) => write!(f, "{}{unit}", self.millis / unit.millis()),
+            None => write!(f, "{}", self.millis),
+        }
+    }
+}
+
+impl fmt::Display for DurationUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            DurationUnit::MilliSecond => "ms",
+            DurationUnit::Second => "s",
+            DurationUnit::Minute => "m",
+        };
+        write!(f, "{s}")
+    }
+}
+
 impl fmt::Display for RetryOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -196,6 +225,140 @@ impl fmt::Display for RetryOption {
     }
 }
 
+impl fmt::Display for OptionKind {
+    /// Formats the option as written in an `[Options]` section: `name: value`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name(), option_value_encoded(self))
+    }
+}
+
+/// Returns the value of an option as written in the source: contrary to
+/// [`OptionKind::value_as_str`], expressions keep their delimiters and templates their escape
+/// sequences.
+fn option_value_encoded(kind: &OptionKind) -> String {
+    match kind {
+        OptionKind::Accept(value)
+        | OptionKind::AcceptEncoding(value)
+        | OptionKind::AwsRegion(value)
+        | OptionKind::AwsService(value)
+        | OptionKind::AwsSigV4(value)
+        | OptionKind::BodyFile(value)
+        | OptionKind::CaCertificate(value)
+        | OptionKind::ClientCert(value)
+        | OptionKind::ClientKey(value)
+        | OptionKind::ConnectTo(value)
+        | OptionKind::ContentType(value)
+        | OptionKind::Form(value)
+        | OptionKind::Json(value)
+        | OptionKind::KeyPassword(value)
+        | OptionKind::LoginOptions(value)
+        | OptionKind::NetRcFile(value)
+        | OptionKind::NoProxy(value)
+        | OptionKind::Output(value)
+        | OptionKind::Proxy(value)
+        | OptionKind::ProxyCaCert(value)
+        | OptionKind::ProxyUser(value)
+        | OptionKind::Query(value)
+        | OptionKind::Range(value)
+        | OptionKind::Referer(value)
+        | OptionKind::RequestMethod(value)
+        | OptionKind::Resolve(value)
+        | OptionKind::SshPrivateKey(value)
+        | OptionKind::SshPublicKey(value)
+        | OptionKind::UnixSocket(value)
+        | OptionKind::Url(value)
+        | OptionKind::User(value)
+        | OptionKind::UserAgent(value) => value.encoded(),
+        OptionKind::DataBinary(value) => format!("@{}", value.encoded()),
+        OptionKind::RemoveResolve(value) => format!("-{}", value.encoded()),
+        OptionKind::AbortOnError(value)
+        | OptionKind::Compressed(value)
+        | OptionKind::ContinueOnError(value)
+        | OptionKind::Crlf(value)
+        | OptionKind::FailWithBody(value)
+        | OptionKind::FollowLocation(value)
+        | OptionKind::FollowLocationTrusted(value)
+        | OptionKind::FreshConnect(value)
+        | OptionKind::GlobOff(value)
+        | OptionKind::HaproxyProtocol(value)
+        | OptionKind::Http10(value)
+        | OptionKind::Http11(value)
+        | OptionKind::Http2(value)
+        | OptionKind::Http3(value)
+        | OptionKind::Insecure(value)
+        | OptionKind::IpV4(value)
+        | OptionKind::IpV6(value)
+        | OptionKind::NetRc(value)
+        | OptionKind::NetRcOptional(value)
+        | OptionKind::NoBuffer(value)
+        | OptionKind::Parallel(value)
+        | OptionKind::PathAsIs(value)
+        | OptionKind::Post301(value)
+        | OptionKind::Post302(value)
+        | OptionKind::Post303(value)
+        | OptionKind::ProxyInsecure(value)
+        | OptionKind::RawResponse(value)
+        | OptionKind::SendFragment(value)
+        | OptionKind::Skip(value)
+        | OptionKind::StopOnFailure(value)
+        | OptionKind::TcpKeepAlive(value)
+        | OptionKind::TcpNoDelay(value)
+        | OptionKind::Verbose(value)
+        | OptionKind::VeryVerbose(value) => match value {
+            BooleanOption::Literal(value) => value.to_string(),
+            BooleanOption::Expression(expr) => format!("{{{{{expr}}}}}"),
+        },
+        OptionKind::ConnectTimeout(value)
+        | OptionKind::Delay(value)
+        | OptionKind::MaxTime(value)
+        | OptionKind::RetryDeadline(value)
+        | OptionKind::RetryInterval(value) => match value {
+            DurationOption::Literal(value) => value.to_string(),
+            DurationOption::Expression(expr) => format!("{{{{{expr}}}}}"),
+        },
+        OptionKind::DnsCacheTtl(value)
+        | OptionKind::Expect100Timeout(value)
+        | OptionKind::HappyEyeballsTimeout(value)
+        | OptionKind::MaxFileSize(value)
+        | OptionKind::MaxRedirect(value)
+        | OptionKind::Repeat(value)
+        | OptionKind::RetryJitter(value)
+        | OptionKind::SpeedLimit(value)
+        | OptionKind::SpeedTime(value)
+        | OptionKind::TcpKeepIdle(value)
+        | OptionKind::TcpKeepIntvl(value) => match value {
+            NaturalOption::Literal(value) => value.to_string(),
+            NaturalOption::Expression(expr) => format!("{{{{{expr}}}}}"),
+        },
+        OptionKind::ConnectRetries(value) | OptionKind::Retry(value) => match value {
+            RetryOption::Literal(value) => value.to_string(),
+            RetryOption::Expression(expr) => format!("{{{{{expr}}}}}"),
+        },
+        OptionKind::Variable(definition) | OptionKind::LocalVariable(definition) => {
+            let value = match &definition.value {
+                VariableValue::String(value) => value.encoded(),
+                value => value.to_string(),
+            };
+            format!(
+                "{}{}={}{value}",
+                definition.name, definition.space0.value, definition.space1.value
+            )
+        }
+        OptionKind::CertType(_)
+        | OptionKind::ContentLength(_)
+        | OptionKind::HttpVersion(_)
+        | OptionKind::IpVersion(_)
+        | OptionKind::KeyType(_)
+        | OptionKind::LimitRate(_)
+        | OptionKind::MaxHeaderSize(_)
+        | OptionKind::RetryOn(_)
+        | OptionKind::RetryOnStatus(_)
+        | OptionKind::TlsMax(_)
+        | OptionKind::TraceBody(_)
+        | OptionKind::VerboseLevel(_) => kind.value_as_str(),
+    }
+}
+
 impl fmt::Display for VariableDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}={}", self.name, self.value)
@@ -209,11 +372,44 @@ impl fmt::Display for VariableValue {
             VariableValue::Bool(value) => value.to_string(),
             VariableValue::Number(n) => n.to_string(),
             VariableValue::String(s) => s.to_string(),
+            VariableValue::List(values) => {
+                let values = values.iter().map(delimited).collect::<Vec<_>>();
+                format!("[{}]", values.join(","))
+            }
+            VariableValue::Object(entries) => {
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| format!("{key}:{}", delimited(value)))
+                    .collect::<Vec<_>>();
+                format!("{{{}}}", entries.join(","))
+            }
+            VariableValue::File(file, default) => {
+                format!("file,{};{}", file.filename, default_suffix(default))
+            }
+            VariableValue::Env(name, default) => format!("env,{name}{}", default_suffix(default)),
         };
         write!(f, "{}", s)
     }
 }
 
+/// Formats the optional `:default` suffix of a variable value read from a file or the environment.
+fn default_suffix(default: &Option<Box<VariableValue>>) -> String {
+    match default {
+        Some(default) => format!(":{}", delimited(default)),
+        None => String::new(),
+    }
+}
+
+/// Formats a variable value nested in a list or an object, with its string delimiters.
+fn delimited(value: &VariableValue) -> String {
+    match value {
+        VariableValue::String(Template {
+            delimiter: Some(d), ..
+        }) => format!("{d}{value}{d}"),
+        _ => value.to_string(),
+    }
+}
+
 impl PredicateFuncValue {
     pub fn name(&self) -> String {
         match self {
@@ -289,6 +485,103 @@ impl fmt::Display for Retry {
     }
 }
 
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)?;
+        if let Some(unit) = self.unit {
+            write!(f, "{unit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ByteUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            ByteUnit::Kilo => "K",
+            ByteUnit::Mega => "M",
+            ByteUnit::Giga => "G",
+            ByteUnit::KiloBit => "Kbps",
+            ByteUnit::MegaBit => "Mbps",
+            ByteUnit::GigaBit => "Gbps",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl fmt::Display for ContentLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentLength::Auto => write!(f, "auto"),
+            ContentLength::Omit => write!(f, "omit"),
+            ContentLength::Fixed(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl fmt::Display for CertType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CertType::Pem => write!(f, "PEM"),
+            CertType::Der => write!(f, "DER"),
+            CertType::P12 => write!(f, "P12"),
+            CertType::Eng => write!(f, "ENG"),
+        }
+    }
+}
+
+impl fmt::Display for HttpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpVersion::Http10 => write!(f, "1.0"),
+            HttpVersion::Http11 => write!(f, "1.1"),
+            HttpVersion::Http2 => write!(f, "2"),
+            HttpVersion::Http3 => write!(f, "3"),
+        }
+    }
+}
+
+impl fmt::Display for IpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpVersion::Auto => write!(f, "auto"),
+            IpVersion::V4 => write!(f, "4"),
+            IpVersion::V6 => write!(f, "6"),
+        }
+    }
+}
+
+impl fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TlsVersion::Tls10 => write!(f, "1.0"),
+            TlsVersion::Tls11 => write!(f, "1.1"),
+            TlsVersion::Tls12 => write!(f, "1.2"),
+            TlsVersion::Tls13 => write!(f, "1.3"),
+        }
+    }
+}
+
+impl fmt::Display for RetryTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RetryTrigger::Asserts => write!(f, "asserts"),
+            RetryTrigger::Transport => write!(f, "transport"),
+            RetryTrigger::All => write!(f, "all"),
+        }
+    }
+}
+
+impl fmt::Display for TraceMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceMode::Off => write!(f, "off"),
+            TraceMode::Ascii => write!(f, "ascii"),
+            TraceMode::Hex => write!(f, "hex"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,7 +190,8 @@ impl ObjectElement {
 }
 
 impl Template {
-    fn encoded(&self) -> String {
+    /// Returns the template as written in the source, with its delimiters and escape sequences.
+    pub(crate) fn encoded(&self) -> String {
         let mut s = String::new();
         if let Some(d) = self.delimiter {
             s.push(d);
@@ -214,37 +214,111 @@ impl HtmlFormatter {
         self.buffer.push(':');
         self.fmt_space(&option.space2);
         match &option.kind {
+            OptionKind::AbortOnError(value) => self.fmt_bool_option(value),
+            OptionKind::Accept(value) => self.fmt_template(value),
+            OptionKind::AcceptEncoding(value) => self.fmt_template(value),
+            OptionKind::AwsRegion(value) => self.fmt_template(value),
+            OptionKind::AwsService(value) => self.fmt_template(value),
             OptionKind::AwsSigV4(value) => self.fmt_template(value),
+            OptionKind::BodyFile(filename) => self.fmt_filename(filename),
             OptionKind::CaCertificate(filename) => self.fmt_filename(filename),
+            OptionKind::CertType(value) => self.fmt_string(&value.to_string()),
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
             OptionKind::Compressed(value) => self.fmt_bool_option(value),
+            OptionKind::ConnectRetries(value) => self.fmt_retry_option(value),
+            OptionKind::ConnectTimeout(value) => self.fmt_duration_option(value),
             OptionKind::ConnectTo(value) => self.fmt_template(value),
-            OptionKind::Delay(value) => self.fmt_natural_option(value),
+            OptionKind::ContentLength(value) => self.fmt_content_length(value),
+            OptionKind::ContentType(value) => self.fmt_template(value),
+            OptionKind::ContinueOnError(value) => self.fmt_bool_option(value),
+            OptionKind::Crlf(value) => self.fmt_bool_option(value),
+            OptionKind::DataBinary(filename) => {
+                self.buffer.push('@');
+                self.fmt_filename(filename);
+            }
+            OptionKind::Delay(value) => self.fmt_duration_option(value),
+            OptionKind::DnsCacheTtl(value) => self.fmt_natural_option(value),
+            OptionKind::Expect100Timeout(value) => self.fmt_natural_option(value),
+            OptionKind::FailWithBody(value) => self.fmt_bool_option(value),
             OptionKind::FollowLocation(value) => self.fmt_bool_option(value),
             OptionKind::FollowLocationTrusted(value) => self.fmt_bool_option(value),
+            OptionKind::Form(value) => self.fmt_template(value),
+            OptionKind::FreshConnect(value) => self.fmt_bool_option(value),
+            OptionKind::GlobOff(value) => self.fmt_bool_option(value),
+            OptionKind::HappyEyeballsTimeout(value) => self.fmt_natural_option(value),
+            OptionKind::HaproxyProtocol(value) => self.fmt_bool_option(value),
             OptionKind::Http10(value) => self.fmt_bool_option(value),
             OptionKind::Http11(value) => self.fmt_bool_option(value),
             OptionKind::Http2(value) => self.fmt_bool_option(value),
             OptionKind::Http3(value) => self.fmt_bool_option(value),
+            OptionKind::HttpVersion(value) => self.fmt_number(value),
             OptionKind::Insecure(value) => self.fmt_bool_option(value),
             OptionKind::IpV4(value) => self.fmt_bool_option(value),
             OptionKind::IpV6(value) => self.fmt_bool_option(value),
+            OptionKind::Json(value) => self.fmt_template(value),
+            OptionKind::IpVersion(value) => self.fmt_string(&value.to_string()),
+            OptionKind::KeyPassword(value) => self.fmt_template(value),
+            OptionKind::KeyType(value) => self.fmt_string(&value.to_string()),
+            OptionKind::LimitRate(value) => self.fmt_number(value),
+            OptionKind::LoginOptions(value) => self.fmt_template(value),
+            OptionKind::MaxFileSize(value) => self.fmt_natural_option(value),
+            OptionKind::MaxHeaderSize(value) => self.fmt_number(value),
             OptionKind::MaxRedirect(value) => self.fmt_natural_option(value),
+            OptionKind::MaxTime(value) => self.fmt_duration_option(value),
             OptionKind::NetRc(value) => self.fmt_bool_option(value),
             OptionKind::NetRcFile(filename) => self.fmt_filename(filename),
             OptionKind::NetRcOptional(value) => self.fmt_bool_option(value),
+            OptionKind::NoBuffer(value) => self.fmt_bool_option(value),
+            OptionKind::NoProxy(value) => self.fmt_template(value),
             OptionKind::Output(filename) => self.fmt_filename(filename),
+            OptionKind::Parallel(value) => self.fmt_bool_option(value),
             OptionKind::PathAsIs(value) => self.fmt_bool_option(value),
+            OptionKind::Post301(value) => self.fmt_bool_option(value),
+            OptionKind::Post302(value) => self.fmt_bool_option(value),
+            OptionKind::Post303(value) => self.fmt_bool_option(value),
             OptionKind::Proxy(value) => self.fmt_template(value),
+            OptionKind::ProxyCaCert(filename) => self.fmt_filename(filename),
+            OptionKind::ProxyInsecure(value) => self.fmt_bool_option(value),
+            OptionKind::ProxyUser(value) => self.fmt_template(value),
+            OptionKind::Query(value) => self.fmt_template(value),
+            OptionKind::Range(value) => self.fmt_template(value),
+            OptionKind::RawResponse(value) => self.fmt_bool_option(value),
+            OptionKind::Referer(value) => self.fmt_template(value),
+            OptionKind::RemoveResolve(value) => {
+                self.buffer.push('-');
+                self.fmt_template(value);
+            }
+            OptionKind::Repeat(value) => self.fmt_natural_option(value),
+            OptionKind::RequestMethod(value) => self.fmt_template(value),
             OptionKind::Resolve(value) => self.fmt_template(value),
             OptionKind::Retry(value) => self.fmt_retry_option(value),
-            OptionKind::RetryInterval(value) => self.fmt_natural_option(value),
+            OptionKind::RetryDeadline(value) => self.fmt_duration_option(value),
+            OptionKind::RetryInterval(value) => self.fmt_duration_option(value),
+            OptionKind::RetryJitter(value) => self.fmt_natural_option(value),
+            OptionKind::RetryOn(value) => self.fmt_string(&value.to_string()),
+            OptionKind::RetryOnStatus(value) => self.fmt_status_codes(value),
+            OptionKind::SendFragment(value) => self.fmt_bool_option(value),
             OptionKind::Skip(value) => self.fmt_bool_option(value),
+            OptionKind::SpeedLimit(value) => self.fmt_natural_option(value),
+            OptionKind::SpeedTime(value) => self.fmt_natural_option(value),
+            OptionKind::SshPrivateKey(filename) => self.fmt_filename(filename),
+            OptionKind::SshPublicKey(filename) => self.fmt_filename(filename),
+            OptionKind::StopOnFailure(value) => self.fmt_bool_option(value),
+            OptionKind::TcpKeepAlive(value) => self.fmt_bool_option(value),
+            OptionKind::TcpKeepIdle(value) => self.fmt_natural_option(value),
+            OptionKind::TcpKeepIntvl(value) => self.fmt_natural_option(value),
+            OptionKind::TcpNoDelay(value) => self.fmt_bool_option(value),
+            OptionKind::TlsMax(value) => self.fmt_number(value),
+            OptionKind::TraceBody(value) => self.fmt_string(&value.to_string()),
             OptionKind::UnixSocket(value) => self.fmt_template(value),
+            OptionKind::Url(value) => self.fmt_template(value),
             OptionKind::User(value) => self.fmt_template(value),
+            OptionKind::UserAgent(value) => self.fmt_template(value),
             OptionKind::Variable(value) => self.fmt_variable_definition(value),
+            OptionKind::LocalVariable(value) => self.fmt_variable_definition(value),
             OptionKind::Verbose(value) => self.fmt_bool_option(value),
+            OptionKind::VerboseLevel(value) => self.fmt_number(value),
             OptionKind::VeryVerbose(value) => self.fmt_bool_option(value),
         };
         self.fmt_span_close();
@@ -266,6 +340,22 @@ impl HtmlFormatter {
         };
     }
 
+    fn fmt_content_length(&mut self, content_length: &ContentLength) {
+        match content_length {
+            ContentLength::Fixed(n) => self.fmt_number(n),
+            _ => self.fmt_string(&content_length.to_string()),
+        }
+    }
+
+    fn fmt_status_codes(&mut self, codes: &[u32]) {
+        for (i, code) in codes.iter().enumerate() {
+            if i > 0 {
+                self.buffer.push(',');
+            }
+            self.fmt_number(code);
+        }
+    }
+
     fn fmt_variable_definition(&mut self, option: &VariableDefinition) {
         self.buffer.push_str(option.name.as_str());
         self.fmt_space(&option.space1);
@@ -279,6 +369,44 @@ impl HtmlFormatter {
             VariableValue::Bool(v) => self.fmt_bool(*v),
             VariableValue::Number(v) => self.fmt_number(v),
             VariableValue::String(t) => self.fmt_template(t),
+            VariableValue::List(values) => {
+                self.buffer.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        self.buffer.push(',');
+                    }
+                    self.fmt_variable_value(value);
+                }
+                self.buffer.push(']');
+            }
+            VariableValue::Object(entries) => {
+                self.buffer.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        self.buffer.push(',');
+                    }
+                    self.buffer.push_str(key);
+                    self.buffer.push(':');
+                    self.fmt_variable_value(value);
+                }
+                self.buffer.push('}');
+            }
+            VariableValue::File(file, default) => {
+                self.fmt_file(file);
+                self.fmt_variable_default(default);
+            }
+            VariableValue::Env(name, default) => {
+                self.buffer.push_str("env,");
+                self.fmt_string(name);
+                self.fmt_variable_default(default);
+            }
+        }
+    }
+
+    fn fmt_variable_default(&mut self, default: &Option<Box<VariableValue>>) {
+        if let Some(default) = default {
+            self.buffer.push(':');
+            self.fmt_variable_value(default);
         }
     }
 
@@ -696,6 +824,13 @@ impl HtmlFormatter {
         }
     }
 
+    fn fmt_duration_option(&mut self, value: &DurationOption) {
+        match value {
+            DurationOption::Literal(value) => self.fmt_span("number", &value.to_string()),
+            DurationOption::Expression(value) => self.fmt_expr(value),
+        }
+    }
+
     fn fmt_number<T: Sized + Display>(&mut self, value: T) {
         self.fmt_span("number", &value.to_string());
     }
@@ -762,7 +897,13 @@ impl HtmlFormatter {
 
     fn fmt_template(&mut self, template: &Template) {
         let s = template.to_encoded_string();
-        self.fmt_string(&escape_xml(&s));
+        // An option value can span several lines with line continuations: we close the current
+        // span line on each newline and open a new one.
+        let s = escape_xml(&s).replace(
+            '\n',
+            "</span></span>\n<span class=\"line\"><span class=\"string\">",
+        );
+        self.fmt_string(&s);
     }
 
     fn fmt_expr(&mut self, expr: &Expr) {
@@ -16,6 +16,7 @@
  *
  */
 use crate::ast::{Pos, SourceInfo};
+use crate::parser::option::option_names;
 use std::cmp;
 
 /// Represents a parser error.
@@ -28,30 +29,58 @@ pub struct Error {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseError {
+    /// The option `name` conflicts with a previous option `other`.
+    ConflictingOption {
+        name: String,
+        other: String,
+    },
+    DataBinaryFilename,
+    DuplicateOption {
+        name: String,
+    },
     DuplicateSection,
     EscapeChar,
-    Expecting { value: String },
+    Expecting {
+        value: String,
+    },
     FileContentType,
     Filename,
     GraphQlVariables,
     HexDigit,
     InvalidCookieAttribute,
-    InvalidOption(String),
+    InvalidOption {
+        name: String,
+        suggestion: Option<String>,
+    },
     Json(JsonErrorVariant),
     JsonPathExpr,
-    Method { name: String },
+    Method {
+        name: String,
+    },
     Multiline,
     OddNumberOfHexDigits,
     Predicate,
     PredicateValue,
-    RegexExpr { message: String },
+    RegexExpr {
+        message: String,
+    },
     RequestSection,
-    RequestSectionName { name: String },
+    RequestSectionName {
+        name: String,
+    },
     ResponseSection,
-    ResponseSectionName { name: String },
+    ResponseSectionName {
+        name: String,
+    },
+    ShadowedConnectTo {
+        source: String,
+    },
     Space,
     Status,
     TemplateVariable,
+    UndeclaredVariable {
+        name: String,
+    },
     Unicode,
     UrlIllegalCharacter(char),
     UrlInvalidStart,
@@ -98,6 +127,9 @@ impl crate::error::Error for Error {
 
     fn description(&self) -> String {
         match self.inner {
+            ParseError::ConflictingOption { .. } => "Validating option".to_string(),
+            ParseError::DataBinaryFilename => "Parsing data-binary option".to_string(),
+            ParseError::DuplicateOption { .. } => "Validating option".to_string(),
             ParseError::DuplicateSection => "Parsing section".to_string(),
             ParseError::EscapeChar => "Parsing escape character".to_string(),
             ParseError::Expecting { .. } => "Parsing literal".to_string(),
@@ -106,7 +138,7 @@ impl crate::error::Error for Error {
             ParseError::GraphQlVariables => "Parsing GraphQL variables".to_string(),
             ParseError::HexDigit => "Parsing hexadecimal number".to_string(),
             ParseError::InvalidCookieAttribute => "Parsing cookie attribute".to_string(),
-            ParseError::InvalidOption(_) => "Parsing option".to_string(),
+            ParseError::InvalidOption { .. } => "Parsing option".to_string(),
             ParseError::Json(_) => "Parsing JSON".to_string(),
             ParseError::JsonPathExpr => "Parsing JSONPath expression".to_string(),
             ParseError::Method { .. } => "Parsing method".to_string(),
@@ -119,9 +151,11 @@ impl crate::error::Error for Error {
             ParseError::RequestSectionName { .. } => "Parsing request section name".to_string(),
             ParseError::ResponseSection => "Parsing section".to_string(),
             ParseError::ResponseSectionName { .. } => "Parsing response section name".to_string(),
+            ParseError::ShadowedConnectTo { .. } => "Validating connect-to option".to_string(),
             ParseError::Space => "Parsing space".to_string(),
             ParseError::Status => "Parsing status code".to_string(),
             ParseError::TemplateVariable => "Parsing template variable".to_string(),
+            ParseError::UndeclaredVariable { .. } => "Validating variable".to_string(),
             ParseError::Unicode => "Parsing unicode literal".to_string(),
             ParseError::UrlIllegalCharacter(_) => "Parsing URL".to_string(),
             ParseError::UrlInvalidStart => "Parsing URL".to_string(),
@@ -133,6 +167,15 @@ impl crate::error::Error for Error {
 
     fn fixme(&self) -> String {
         match &self.inner {
+            ParseError::ConflictingOption { name, other } => {
+                format!("the option <{name}> conflicts with the option <{other}>")
+            }
+            ParseError::DataBinaryFilename => {
+                "expecting '@' followed by a filename; use `data` for inline content".to_string()
+            }
+            ParseError::DuplicateOption { name } => {
+                format!("the option <{name}> is already defined; only the last value is used")
+            }
             ParseError::DuplicateSection => "the section is already defined".to_string(),
             ParseError::EscapeChar => "the escaping sequence is not valid".to_string(),
             ParseError::Expecting { value } => format!("expecting '{value}'"),
@@ -143,39 +186,12 @@ impl crate::error::Error for Error {
             }
             ParseError::HexDigit => "expecting a valid hexadecimal number".to_string(),
             ParseError::InvalidCookieAttribute => "the cookie attribute is not valid".to_string(),
-            ParseError::InvalidOption(name) => {
-                let valid_values = [
-                    "aws-sigv4",
-                    "cacert",
-                    "cert",
-                    "compressed",
-                    "connect-to",
-                    "delay",
-                    "insecure",
-                    "http1.0",
-                    "http1.1",
-                    "http2",
-                    "http3",
-                    "ipv4",
-                    "ipv6",
-                    "key",
-                    "location",
-                    "max-redirs",
-                    "output",
-                    "path-as-is",
-                    "proxy",
-                    "resolve",
-                    "retry",
-                    "retry-interval",
-                    "skip",
-                    "unix-socket",
-                    "variable",
-                    "verbose",
-                    "very-verbose",
-                ];
-                let default = format!("Valid values are {}", valid_values.join(", "));
-                let did_you_mean = did_you_mean(&valid_values, name.as_str(), &default);
-                format!("the option name is not valid. {did_you_mean}")
+            ParseError::InvalidOption { name, suggestion } => {
+                let hint = match suggestion {
+                    Some(suggestion) => format!("Did you mean {suggestion}?"),
+                    None => format!("Valid values are {}", option_names().join(", ")),
+                };
+                format!("the option name <{name}> is not valid. {hint}")
             }
             ParseError::Json(variant) => match variant {
                 JsonErrorVariant::TrailingComma => "trailing comma is not allowed".to_string(),
@@ -222,9 +238,15 @@ impl crate::error::Error for Error {
                 let did_your_mean = did_you_mean(&valid_values, name.as_str(), default);
                 format!("the section is not valid. {did_your_mean}")
             }
+            ParseError::ShadowedConnectTo { source } => {
+                format!("the mapping of <{source}> is overridden by a later connect-to option")
+            }
             ParseError::Space => "expecting a space".to_string(),
             ParseError::Status => "HTTP status code is not valid".to_string(),
             ParseError::TemplateVariable => "expecting a variable".to_string(),
+            ParseError::UndeclaredVariable { name } => {
+                format!("the variable <{name}> is not declared")
+            }
             ParseError::Unicode => "Invalid unicode literal".to_string(),
             ParseError::UrlIllegalCharacter(c) => format!("illegal character <{c}>"),
             ParseError::UrlInvalidStart => "expecting http://, https:// or {{".to_string(),
@@ -238,24 +260,32 @@ impl crate::error::Error for Error {
 }
 
 fn did_you_mean(valid_values: &[&str], actual: &str, default: &str) -> String {
-    if let Some(suggest) = suggestion(valid_values, actual) {
+    if let Some(suggest) = suggestion(valid_values, actual, 1) {
         format!("Did you mean {suggest}?")
     } else {
         default.to_string()
     }
 }
 
-fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
-    for value in valid_values {
-        if levenshtein_distance(
-            value.to_lowercase().as_str(),
-            actual.to_lowercase().as_str(),
-        ) < 2
-        {
-            return Some(value.to_string());
-        }
-    }
-    None
+/// Returns the value of `valid_values` closest to `actual`, if it's at most `max_distance` edits
+/// away. Short values need proportionally fewer edits, so that unrelated names are not suggested.
+pub(crate) fn suggestion(
+    valid_values: &[&str],
+    actual: &str,
+    max_distance: usize,
+) -> Option<String> {
+    valid_values
+        .iter()
+        .map(|value| {
+            let distance = levenshtein_distance(
+                value.to_lowercase().as_str(),
+                actual.to_lowercase().as_str(),
+            );
+            (distance, value)
+        })
+        .filter(|(distance, _)| *distance <= max_distance && *distance * 2 < actual.len())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, value)| value.to_string())
 }
 
 // From https://en.wikibooks.org/wiki/Algorithm_Implementation/Strings/Levenshtein_distance#Rust
@@ -301,17 +331,31 @@ mod tests {
     fn test_suggestion() {
         let valid_values = ["Captures", "Asserts"];
         assert_eq!(
-            suggestion(&valid_values, "Asserts"),
+            suggestion(&valid_values, "Asserts", 1),
             Some("Asserts".to_string())
         );
         assert_eq!(
-            suggestion(&valid_values, "Assert"),
+            suggestion(&valid_values, "Assert", 1),
             Some("Asserts".to_string())
         );
         assert_eq!(
-            suggestion(&valid_values, "assert"),
+            suggestion(&valid_values, "assert", 1),
             Some("Asserts".to_string())
         );
-        assert_eq!(suggestion(&valid_values, "asser"), None);
+        assert_eq!(suggestion(&valid_values, "asser", 1), None);
+
+        assert_eq!(
+            suggestion(&option_names(), "verbsoe", 2),
+            Some("verbose".to_string())
+        );
+        assert_eq!(
+            suggestion(&option_names(), "very_verbose", 2),
+            Some("very-verbose".to_string())
+        );
+        assert_eq!(suggestion(&option_names(), "foo", 2), None);
+        assert_eq!(
+            suggestion(&option_names(), "forn", 2),
+            Some("form".to_string())
+        );
     }
 }
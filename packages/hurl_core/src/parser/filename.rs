@@ -27,8 +27,12 @@ use crate::parser::{string, ParseResult};
 /// A few characters need to be escaped such as space
 /// for example: file\ with\ space.txt
 /// This is very similar to the behaviour in a standard shell.
+/// A filename can also be double-quoted, as a quoted string: "file with space.txt"
 ///
 pub fn parse(reader: &mut Reader) -> ParseResult<Template> {
+    if reader.peek() == Some('"') {
+        return string::quoted_template(reader).map_err(|e| e.non_recoverable());
+    }
     let start = reader.state;
 
     let mut elements = vec![];
@@ -180,6 +184,42 @@ mod tests {
         assert_eq!(reader.state.cursor, 8);
     }
 
+    #[test]
+    fn test_quoted_filename() {
+        let mut reader = Reader::new("\"/home/my certs/ca.pem\"");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: "/home/my certs/ca.pem".to_string(),
+                    encoded: "/home/my certs/ca.pem".to_string()
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 24)),
+            }
+        );
+        assert_eq!(reader.state.cursor, 23);
+
+        let mut reader = Reader::new("\"my \\\"quoted\\\" file.bin\";");
+        let filename = parse(&mut reader).unwrap();
+        assert_eq!(filename.delimiter, Some('"'));
+        assert_eq!(
+            filename.elements,
+            vec![TemplateElement::String {
+                value: "my \"quoted\" file.bin".to_string(),
+                encoded: "my \\\"quoted\\\" file.bin".to_string()
+            }]
+        );
+        assert_eq!(reader.peek(), Some(';'));
+    }
+
+    #[test]
+    fn test_quoted_filename_error() {
+        let mut reader = Reader::new("\"/home/my certs/ca.pem");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_include_space() {
         let mut reader = Reader::new("file\\ with\\ spaces");
@@ -28,6 +28,11 @@ pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
     number_value as parse_json_number, parse as parse_json,
 };
+pub use self::option::{
+    check_option_value, format_option, parse_entry_option, parse_entry_option_with, sort_options,
+    supported_options, validate_all, validate_connect_to, validate_options, OptionParseError,
+    OptionSpec, OptionValueType, ParseOptions,
+};
 pub use self::reader::Reader;
 pub use self::template::templatize;
 use crate::ast::HurlFile;
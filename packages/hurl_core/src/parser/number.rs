@@ -118,7 +118,7 @@ pub fn number(reader: &mut Reader) -> ParseResult<Number> {
     } else {
         match format!("{sign}{integer_digits}").parse() {
             Ok(value) => Ok(Number::Integer(value)),
-            Err(_) => Ok(Number::BigInteger(integer_digits)),
+            Err(_) => Ok(Number::BigInteger(format!("{sign}{integer_digits}"))),
         }
     }
 }
@@ -196,6 +196,13 @@ mod tests {
         let mut reader = Reader::new("-10x");
         assert_eq!(number(&mut reader).unwrap(), Number::Integer(-10));
         assert_eq!(reader.state.cursor, 3);
+
+        let mut reader = Reader::new("-12345678901234567890");
+        assert_eq!(
+            number(&mut reader).unwrap(),
+            Number::BigInteger("-12345678901234567890".to_string())
+        );
+        assert_eq!(reader.state.cursor, 21);
     }
 
     #[test]
@@ -15,6 +15,10 @@
  * limitations under the License.
  *
  */
+use std::collections::HashSet;
+use std::fmt;
+use std::net::IpAddr;
+
 use crate::ast::*;
 use crate::parser::combinators::*;
 use crate::parser::error::*;
@@ -22,10 +26,29 @@ use crate::parser::number::{integer, natural, number};
 use crate::parser::primitives::*;
 use crate::parser::reader::Reader;
 use crate::parser::string::*;
-use crate::parser::{expr, filename, filename_password, ParseResult};
+use crate::parser::{expr, filename, filename_password, ParseFunc, ParseResult};
 
 /// Parse an option in an `[Options]` section.
 pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
+    parse_with(reader, &ParseOptions::default())
+}
+
+/// Options of the parsing of an entry option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// In strict mode, an unknown option is a non-recoverable error. Otherwise, the error is
+    /// recoverable and callers can skip the unknown option.
+    pub strict: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions { strict: true }
+    }
+}
+
+/// Parse an option in an `[Options]` section, with parsing `options`.
+fn parse_with(reader: &mut Reader, options: &ParseOptions) -> ParseResult<EntryOption> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let start = reader.state.pos;
@@ -37,47 +60,15 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     let space1 = zero_or_more_spaces(reader)?;
     try_literal(":", reader)?;
     let space2 = zero_or_more_spaces(reader)?;
-    let kind = match option.as_str() {
-        "aws-sigv4" => option_aws_sigv4(reader)?,
-        "cacert" => option_cacert(reader)?,
-        "cert" => option_cert(reader)?,
-        "compressed" => option_compressed(reader)?,
-        "connect-to" => option_connect_to(reader)?,
-        "delay" => option_delay(reader)?,
-        "insecure" => option_insecure(reader)?,
-        "http1.0" => option_http_10(reader)?,
-        "http1.1" => option_http_11(reader)?,
-        "http2" => option_http_2(reader)?,
-        "http3" => option_http_3(reader)?,
-        "ipv4" => option_ipv4(reader)?,
-        "ipv6" => option_ipv6(reader)?,
-        "key" => option_key(reader)?,
-        "location" => option_follow_location(reader)?,
-        "location-trusted" => option_follow_location_trusted(reader)?,
-        "max-redirs" => option_max_redirect(reader)?,
-        "netrc" => option_netrc(reader)?,
-        "netrc-file" => option_netrc_file(reader)?,
-        "netrc-optional" => option_netrc_optional(reader)?,
-        "output" => option_output(reader)?,
-        "path-as-is" => option_path_as_is(reader)?,
-        "proxy" => option_proxy(reader)?,
-        "resolve" => option_resolve(reader)?,
-        "retry" => option_retry(reader)?,
-        "retry-interval" => option_retry_interval(reader)?,
-        "skip" => option_skip(reader)?,
-        "unix-socket" => option_unix_socket(reader)?,
-        "user" => option_user(reader)?,
-        "variable" => option_variable(reader)?,
-        "verbose" => option_verbose(reader)?,
-        "very-verbose" => option_very_verbose(reader)?,
-        _ => {
-            return Err(Error::new(
-                start,
-                false,
-                ParseError::InvalidOption(option.to_string()),
-            ))
-        }
+    let Some(parser) = option_parser(&option) else {
+        let suggestion = suggestion(&option_names(), &option, 2);
+        let inner = ParseError::InvalidOption {
+            name: option.to_string(),
+            suggestion,
+        };
+        return Err(Error::new(start, !options.strict, inner));
     };
+    let kind = parser(reader)?;
 
     let line_terminator0 = line_terminator(reader)?;
     Ok(EntryOption {
@@ -90,11 +81,376 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     })
 }
 
+/// Type of an option value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionValueType {
+    /// A boolean, like `insecure: true`.
+    Bool,
+    /// A non negative integer, like `max-redirs: 10`.
+    Natural,
+    /// A duration, in milliseconds or with a unit, like `delay: 2s`.
+    Duration,
+    /// A filename, like `cacert: ca.pem`.
+    Filename,
+    /// A string template, like `user-agent: hurl/{{version}}`.
+    Template,
+    /// A value with a specific syntax, like `retry: infinite` or `variable: name=value`.
+    Custom,
+}
+
+/// Specification of an entry option: its name, the type of its value and its parser.
+#[derive(Clone, Copy, Debug)]
+pub struct OptionSpec {
+    /// Name of the option, as written in an `[Options]` section.
+    pub name: &'static str,
+    /// Type of the option value.
+    pub value_type: OptionValueType,
+    parser: ParseFunc<OptionKind>,
+}
+
+impl OptionSpec {
+    const fn new(
+        name: &'static str,
+        value_type: OptionValueType,
+        parser: ParseFunc<OptionKind>,
+    ) -> Self {
+        OptionSpec {
+            name,
+            value_type,
+            parser,
+        }
+    }
+
+    const fn bool(name: &'static str, parser: ParseFunc<OptionKind>) -> Self {
+        OptionSpec::new(name, OptionValueType::Bool, parser)
+    }
+
+    const fn natural(name: &'static str, parser: ParseFunc<OptionKind>) -> Self {
+        OptionSpec::new(name, OptionValueType::Natural, parser)
+    }
+
+    const fn duration(name: &'static str, parser: ParseFunc<OptionKind>) -> Self {
+        OptionSpec::new(name, OptionValueType::Duration, parser)
+    }
+
+    const fn filename(name: &'static str, parser: ParseFunc<OptionKind>) -> Self {
+        OptionSpec::new(name, OptionValueType::Filename, parser)
+    }
+
+    const fn template(name: &'static str, parser: ParseFunc<OptionKind>) -> Self {
+        OptionSpec::new(name, OptionValueType::Template, parser)
+    }
+
+    const fn custom(name: &'static str, parser: ParseFunc<OptionKind>) -> Self {
+        OptionSpec::new(name, OptionValueType::Custom, parser)
+    }
+
+    /// Returns `true` if the option value may contain a secret (credentials, passphrase...).
+    pub fn is_secret(&self) -> bool {
+        OptionKind::is_secret_name(self.name)
+    }
+}
+
+/// Supported options, by option name, with the parsers of their values.
+///
+/// Parsing a new option is a one-line entry in this table.
+const OPTION_SPECS: &[OptionSpec] = &[
+    OptionSpec::bool("abort-on-error", option_abort_on_error),
+    OptionSpec::template("accept", option_accept),
+    OptionSpec::template("accept-encoding", option_accept_encoding),
+    OptionSpec::template("aws-region", option_aws_region),
+    OptionSpec::template("aws-service", option_aws_service),
+    OptionSpec::template("aws-sigv4", option_aws_sigv4),
+    OptionSpec::filename("body-file", option_body_file),
+    OptionSpec::filename("cacert", option_cacert),
+    OptionSpec::filename("cert", option_cert),
+    OptionSpec::custom("cert-type", option_cert_type),
+    OptionSpec::bool("compressed", option_compressed),
+    OptionSpec::custom("connect-retries", option_connect_retries),
+    OptionSpec::duration("connect-timeout", option_connect_timeout),
+    OptionSpec::template("connect-to", option_connect_to),
+    OptionSpec::custom("content-length", option_content_length),
+    OptionSpec::template("content-type", option_content_type),
+    OptionSpec::bool("continue-on-error", option_continue_on_error),
+    OptionSpec::bool("crlf", option_crlf),
+    OptionSpec::filename("data-binary", option_data_binary),
+    OptionSpec::duration("delay", option_delay),
+    OptionSpec::natural("dns-cache-ttl", option_dns_cache_ttl),
+    OptionSpec::natural("expect-100-timeout", option_expect_100_timeout),
+    OptionSpec::bool("fail-with-body", option_fail_with_body),
+    OptionSpec::template("form", option_form),
+    OptionSpec::bool("fresh-connect", option_fresh_connect),
+    OptionSpec::bool("globoff", option_glob_off),
+    OptionSpec::natural("happy-eyeballs-timeout", option_happy_eyeballs_timeout),
+    OptionSpec::bool("haproxy-protocol", option_haproxy_protocol),
+    OptionSpec::custom("http-version", option_http_version),
+    OptionSpec::bool("http1.0", option_http_10),
+    OptionSpec::bool("http1.1", option_http_11),
+    OptionSpec::bool("http2", option_http_2),
+    OptionSpec::bool("http3", option_http_3),
+    OptionSpec::bool("insecure", option_insecure),
+    OptionSpec::custom("ip-version", option_ip_version),
+    OptionSpec::bool("ipv4", option_ipv4),
+    OptionSpec::bool("ipv6", option_ipv6),
+    OptionSpec::template("json", option_json),
+    OptionSpec::filename("key", option_key),
+    OptionSpec::template("key-password", option_key_password),
+    OptionSpec::custom("key-type", option_key_type),
+    OptionSpec::custom("limit-rate", option_limit_rate),
+    OptionSpec::bool("location", option_follow_location),
+    OptionSpec::bool("location-trusted", option_follow_location_trusted),
+    OptionSpec::template("login-options", option_login_options),
+    OptionSpec::natural("max-filesize", option_max_filesize),
+    OptionSpec::custom("max-header-size", option_max_header_size),
+    OptionSpec::natural("max-redirs", option_max_redirect),
+    OptionSpec::duration("max-time", option_max_time),
+    OptionSpec::bool("netrc", option_netrc),
+    OptionSpec::template("netrc-file", option_netrc_file),
+    OptionSpec::bool("netrc-optional", option_netrc_optional),
+    OptionSpec::bool("no-buffer", option_no_buffer),
+    OptionSpec::template("noproxy", option_noproxy),
+    OptionSpec::filename("output", option_output),
+    OptionSpec::bool("parallel", option_parallel),
+    OptionSpec::bool("path-as-is", option_path_as_is),
+    OptionSpec::bool("post301", option_post301),
+    OptionSpec::bool("post302", option_post302),
+    OptionSpec::bool("post303", option_post303),
+    OptionSpec::template("proxy", option_proxy),
+    OptionSpec::filename("proxy-cacert", option_proxy_cacert),
+    OptionSpec::bool("proxy-insecure", option_proxy_insecure),
+    OptionSpec::template("proxy-user", option_proxy_user),
+    OptionSpec::template("query", option_query),
+    OptionSpec::template("range", option_range),
+    OptionSpec::bool("raw", option_raw_response),
+    OptionSpec::template("referer", option_referer),
+    OptionSpec::natural("repeat", option_repeat),
+    OptionSpec::template("request", option_request_method),
+    OptionSpec::template("resolve", option_resolve),
+    OptionSpec::custom("retry", option_retry),
+    OptionSpec::duration("retry-deadline", option_retry_deadline),
+    OptionSpec::duration("retry-interval", option_retry_interval),
+    OptionSpec::natural("retry-jitter", option_retry_jitter),
+    OptionSpec::custom("retry-on", option_retry_on),
+    OptionSpec::custom("retry-on-status", option_retry_on_status),
+    OptionSpec::bool("send-fragment", option_send_fragment),
+    OptionSpec::bool("skip", option_skip),
+    OptionSpec::natural("speed-limit", option_speed_limit),
+    OptionSpec::natural("speed-time", option_speed_time),
+    OptionSpec::filename("ssh-private-key", option_ssh_private_key),
+    OptionSpec::filename("ssh-public-key", option_ssh_public_key),
+    OptionSpec::bool("stop-on-failure", option_stop_on_failure),
+    OptionSpec::bool("tcp-keepalive", option_tcp_keepalive),
+    OptionSpec::natural("tcp-keepidle", option_tcp_keepidle),
+    OptionSpec::natural("tcp-keepintvl", option_tcp_keepintvl),
+    OptionSpec::bool("tcp-nodelay", option_tcp_nodelay),
+    OptionSpec::custom("tls-max", option_tls_max),
+    OptionSpec::custom("trace-body", option_trace_body),
+    OptionSpec::template("unix-socket", option_unix_socket),
+    OptionSpec::template("url", option_url),
+    OptionSpec::template("user", option_user),
+    OptionSpec::template("user-agent", option_user_agent),
+    OptionSpec::custom("variable", option_variable),
+    OptionSpec::custom("variable-local", option_local_variable),
+    OptionSpec::custom("verbose", option_verbose),
+    OptionSpec::bool("very-verbose", option_very_verbose),
+];
+
+/// Returns all the options supported in an `[Options]` section, sorted by name.
+pub fn supported_options() -> &'static [OptionSpec] {
+    OPTION_SPECS
+}
+
+/// Returns the names of all the options supported in an `[Options]` section, sorted by name.
+pub(crate) fn option_names() -> Vec<&'static str> {
+    OPTION_SPECS.iter().map(|spec| spec.name).collect()
+}
+
+/// Returns the parser of the option value for the option `name`, if `name` is a valid option.
+fn option_parser(name: &str) -> Option<ParseFunc<OptionKind>> {
+    OPTION_SPECS
+        .iter()
+        .find(|spec| spec.name == name)
+        .map(|spec| spec.parser)
+}
+
+/// Checks that `value` is a valid value for the option `name`.
+pub fn check_option_value(name: &str, value: &str) -> Result<(), ParseError> {
+    let Some(parser) = option_parser(name) else {
+        let suggestion = suggestion(&option_names(), name, 2);
+        return Err(ParseError::InvalidOption {
+            name: name.to_string(),
+            suggestion,
+        });
+    };
+    let mut reader = Reader::new(value);
+    parser(&mut reader).map_err(|e| e.inner)?;
+    line_terminator(&mut reader).map_err(|e| e.inner)?;
+    Ok(())
+}
+
+/// An error returned by [`parse_entry_option`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionParseError {
+    /// Position of the error in the parsed input.
+    pub pos: Pos,
+    /// Description of the error, suitable to be displayed to a user.
+    pub message: String,
+    /// Whether the error is recoverable, i.e. the input is not an option at all and could be
+    /// another construction, rather than an invalid option.
+    pub recoverable: bool,
+}
+
+impl From<Error> for OptionParseError {
+    fn from(error: Error) -> Self {
+        OptionParseError {
+            pos: error.pos,
+            message: crate::error::Error::fixme(&error),
+            recoverable: error.recoverable,
+        }
+    }
+}
+
+impl fmt::Display for OptionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.pos.line, self.pos.column, self.message)
+    }
+}
+
+/// Parses a single option line `input` (ex: `retry: 5`), as written in an `[Options]` section.
+///
+/// This is the entry point for tools (editors, linters...) validating options outside of a
+/// Hurl file. The whole input must be consumed: trailing content after the option is an error.
+pub fn parse_entry_option(input: &str) -> Result<EntryOption, OptionParseError> {
+    parse_entry_option_with(input, &ParseOptions::default())
+}
+
+/// Parses a single option line `input`, like [`parse_entry_option`], with parsing `options`.
+///
+/// With a non strict parsing, an unknown option is a recoverable error, so a tool can report it
+/// as a warning and go on.
+pub fn parse_entry_option_with(
+    input: &str,
+    options: &ParseOptions,
+) -> Result<EntryOption, OptionParseError> {
+    let mut reader = Reader::new(input);
+    let option = parse_with(&mut reader, options)?;
+    if !reader.is_eof() {
+        let inner = ParseError::Expecting {
+            value: "end of option".to_string(),
+        };
+        return Err(Error::new(reader.state.pos, false, inner).into());
+    }
+    Ok(option)
+}
+
+/// Formats `option` with a canonical spacing: no space before the colon and exactly one space
+/// after it.
+///
+/// The indentation, the value and the comments of the option are kept as written, so formatting
+/// an already formatted option doesn't change it.
+pub fn format_option(option: &EntryOption) -> String {
+    let mut s = String::new();
+    for lt in &option.line_terminators {
+        s.push_str(&format_line_terminator(lt));
+    }
+    s.push_str(&option.space0.value);
+    s.push_str(&option.kind.to_string());
+    s.push_str(&format_line_terminator(&option.line_terminator0));
+    s
+}
+
+fn format_line_terminator(lt: &LineTerminator) -> String {
+    let comment = match &lt.comment {
+        Some(comment) => format!("#{}", comment.value),
+        None => String::new(),
+    };
+    format!("{}{comment}{}", lt.space0.value, lt.newline.value)
+}
+
+/// Parses an unquoted option value.
+///
+/// A long value can be split on several lines with a trailing `\`: the line continuation and
+/// the leading spaces of the next line are not part of the value, but are kept in the encoded
+/// string, so the option can be formatted back as written.
+fn option_template(reader: &mut Reader) -> ParseResult<Template> {
+    let mut template = unquoted_template_until(reader, is_line_continuation)?;
+    loop {
+        let save = reader.state;
+        let spaces = zero_or_more_spaces(reader)?;
+        let start = reader.state.cursor;
+        if !reader.try_literal("\\\n") && !reader.try_literal("\\\r\n") {
+            reader.state = save;
+            break;
+        }
+        zero_or_more_spaces(reader)?;
+        let continuation = reader.peek_back(start);
+        let next = unquoted_template_until(reader, is_line_continuation)?;
+
+        let mut elements = vec![TemplateElement::String {
+            value: spaces.value.clone(),
+            encoded: format!("{}{continuation}", spaces.value),
+        }];
+        elements.extend(next.elements);
+        for element in elements {
+            match (template.elements.last_mut(), element) {
+                (
+                    Some(TemplateElement::String { value, encoded }),
+                    TemplateElement::String {
+                        value: next_value,
+                        encoded: next_encoded,
+                    },
+                ) => {
+                    value.push_str(&next_value);
+                    encoded.push_str(&next_encoded);
+                }
+                (_, element) => template.elements.push(element),
+            }
+        }
+        template.source_info.end = next.source_info.end;
+    }
+    Ok(template)
+}
+
+/// Returns true if `reader` is at a line continuation, a `\` at the end of the line.
+fn is_line_continuation(reader: &Reader) -> bool {
+    reader.peek_n(2) == "\\\n" || reader.peek_n(3) == "\\\r\n"
+}
+
+fn option_abort_on_error(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::AbortOnError(value))
+}
+
+fn option_accept(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::Accept(value))
+}
+
+fn option_accept_encoding(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::AcceptEncoding(value))
+}
+
+fn option_aws_region(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::AwsRegion(value))
+}
+
+fn option_aws_service(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::AwsService(value))
+}
+
 fn option_aws_sigv4(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = unquoted_template(reader)?;
+    let value = option_template(reader)?;
     Ok(OptionKind::AwsSigV4(value))
 }
 
+fn option_body_file(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::BodyFile(value))
+}
+
 fn option_cacert(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename::parse(reader)?;
     Ok(OptionKind::CaCertificate(value))
@@ -105,21 +461,87 @@ fn option_cert(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::ClientCert(value))
 }
 
+fn option_cert_type(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(cert_type, reader)?;
+    Ok(OptionKind::CertType(value))
+}
+
 fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::Compressed(value))
 }
 
+fn option_connect_retries(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = retry_option(reader)?;
+    Ok(OptionKind::ConnectRetries(value))
+}
+
+fn option_connect_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(duration_option, reader)?;
+    Ok(OptionKind::ConnectTimeout(value))
+}
+
 fn option_connect_to(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = unquoted_template(reader)?;
+    let value = option_template(reader)?;
+    // Templated values are only known at runtime.
+    if template_variable_refs(&value).is_empty() {
+        if let Err(expecting) = check_connect_to(&value.to_string()) {
+            let inner = ParseError::Expecting { value: expecting };
+            return Err(Error::new(value.source_info.start, false, inner));
+        }
+    }
     Ok(OptionKind::ConnectTo(value))
 }
 
+fn option_content_length(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(content_length, reader)?;
+    Ok(OptionKind::ContentLength(value))
+}
+
+fn option_content_type(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::ContentType(value))
+}
+
+fn option_continue_on_error(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::ContinueOnError(value))
+}
+
+fn option_crlf(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::Crlf(value))
+}
+
+fn option_data_binary(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let pos = reader.state.pos;
+    if try_literal("@", reader).is_err() {
+        return Err(Error::new(pos, false, ParseError::DataBinaryFilename));
+    }
+    let filename = filename::parse(reader)?;
+    Ok(OptionKind::DataBinary(filename))
+}
+
 fn option_delay(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = natural_option(reader)?;
+    let value = nonrecover(duration_option, reader)?;
     Ok(OptionKind::Delay(value))
 }
 
+fn option_dns_cache_ttl(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::DnsCacheTtl(value))
+}
+
+fn option_expect_100_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::Expect100Timeout(value))
+}
+
+fn option_fail_with_body(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::FailWithBody(value))
+}
+
 fn option_follow_location(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::FollowLocation(value))
@@ -130,6 +552,31 @@ fn option_follow_location_trusted(reader: &mut Reader) -> ParseResult<OptionKind
     Ok(OptionKind::FollowLocationTrusted(value))
 }
 
+fn option_form(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::Form(value))
+}
+
+fn option_fresh_connect(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::FreshConnect(value))
+}
+
+fn option_glob_off(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::GlobOff(value))
+}
+
+fn option_happy_eyeballs_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::HappyEyeballsTimeout(value))
+}
+
+fn option_haproxy_protocol(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::HaproxyProtocol(value))
+}
+
 fn option_http_10(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::Http10(value))
@@ -150,11 +597,21 @@ fn option_http_3(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Http3(value))
 }
 
+fn option_http_version(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(http_version, reader)?;
+    Ok(OptionKind::HttpVersion(value))
+}
+
 fn option_insecure(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::Insecure(value))
 }
 
+fn option_ip_version(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(ip_version, reader)?;
+    Ok(OptionKind::IpVersion(value))
+}
+
 fn option_ipv4(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::IpV4(value))
@@ -165,23 +622,63 @@ fn option_ipv6(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::IpV6(value))
 }
 
+fn option_json(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::Json(value))
+}
+
 fn option_key(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename::parse(reader)?;
     Ok(OptionKind::ClientKey(value))
 }
 
+fn option_key_password(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::KeyPassword(value))
+}
+
+fn option_key_type(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(cert_type, reader)?;
+    Ok(OptionKind::KeyType(value))
+}
+
+fn option_limit_rate(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(byte_size, reader)?;
+    Ok(OptionKind::LimitRate(value))
+}
+
+fn option_login_options(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::LoginOptions(value))
+}
+
+fn option_max_filesize(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::MaxFileSize(value))
+}
+
+fn option_max_header_size(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(byte_size, reader)?;
+    Ok(OptionKind::MaxHeaderSize(value))
+}
+
 fn option_max_redirect(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(natural_option, reader)?;
     Ok(OptionKind::MaxRedirect(value))
 }
 
+fn option_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(duration_option, reader)?;
+    Ok(OptionKind::MaxTime(value))
+}
+
 fn option_netrc(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::NetRc(value))
 }
 
 fn option_netrc_file(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = unquoted_template(reader)?;
+    let value = option_template(reader)?;
     Ok(OptionKind::NetRcFile(value))
 }
 
@@ -190,23 +687,124 @@ fn option_netrc_optional(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::NetRcOptional(value))
 }
 
+fn option_no_buffer(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::NoBuffer(value))
+}
+
+fn option_noproxy(reader: &mut Reader) -> ParseResult<OptionKind> {
+    // The host list can be quoted, like with curl: `noproxy: "localhost,.internal"`.
+    let value = choice(&[quoted_template, option_template], reader)?;
+    Ok(OptionKind::NoProxy(value))
+}
+
 fn option_output(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename::parse(reader)?;
     Ok(OptionKind::Output(value))
 }
 
+fn option_parallel(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::Parallel(value))
+}
+
 fn option_path_as_is(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::PathAsIs(value))
 }
 
+fn option_post301(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::Post301(value))
+}
+
+fn option_post302(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::Post302(value))
+}
+
+fn option_post303(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::Post303(value))
+}
+
 fn option_proxy(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = unquoted_template(reader)?;
+    let value = option_template(reader)?;
     Ok(OptionKind::Proxy(value))
 }
 
+fn option_proxy_cacert(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::ProxyCaCert(value))
+}
+
+fn option_proxy_insecure(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::ProxyInsecure(value))
+}
+
+fn option_proxy_user(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::ProxyUser(value))
+}
+
+fn option_query(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = choice(&[quoted_template, option_template], reader)?;
+    Ok(OptionKind::Query(value))
+}
+
+fn option_range(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::Range(value))
+}
+
+fn option_raw_response(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::RawResponse(value))
+}
+
+fn option_referer(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::Referer(value))
+}
+
+fn option_repeat(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::Repeat(value))
+}
+
+fn option_request_method(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    // Templated values are only known at runtime.
+    if template_variable_refs(&value).is_empty() && !is_method_token(&value.to_string()) {
+        let inner = ParseError::Expecting {
+            value: "uppercase HTTP method".to_string(),
+        };
+        return Err(Error::new(value.source_info.start, false, inner));
+    }
+    Ok(OptionKind::RequestMethod(value))
+}
+
 fn option_resolve(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = unquoted_template(reader)?;
+    // A leading `-` removes a previous entry, like curl's `--resolve -HOST:PORT`.
+    if try_literal("-", reader).is_ok() {
+        let value = option_template(reader)?;
+        if template_variable_refs(&value).is_empty() {
+            if let Err(expecting) = check_remove_resolve(&value.to_string()) {
+                let inner = ParseError::Expecting { value: expecting };
+                return Err(Error::new(value.source_info.start, false, inner));
+            }
+        }
+        return Ok(OptionKind::RemoveResolve(value));
+    }
+    let value = option_template(reader)?;
+    // Templated values are only known at runtime.
+    if template_variable_refs(&value).is_empty() {
+        if let Err(expecting) = check_resolve(&value.to_string()) {
+            let inner = ParseError::Expecting { value: expecting };
+            return Err(Error::new(value.source_info.start, false, inner));
+        }
+    }
     Ok(OptionKind::Resolve(value))
 }
 
@@ -215,63 +813,452 @@ fn option_retry(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Retry(value))
 }
 
+fn option_retry_deadline(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(duration_option, reader)?;
+    Ok(OptionKind::RetryDeadline(value))
+}
+
 fn option_retry_interval(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = nonrecover(natural_option, reader)?;
+    let value = nonrecover(duration_option, reader)?;
     Ok(OptionKind::RetryInterval(value))
 }
 
+fn option_retry_jitter(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let start = reader.state.pos;
+    let value = nonrecover(natural_option, reader)?;
+    if let NaturalOption::Literal(value) = value {
+        if value > 100 {
+            let inner = ParseError::Expecting {
+                value: "percentage between 0 and 100".to_string(),
+            };
+            return Err(Error::new(start, false, inner));
+        }
+    }
+    Ok(OptionKind::RetryJitter(value))
+}
+
+fn option_retry_on(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = retry_trigger(reader)?;
+    Ok(OptionKind::RetryOn(value))
+}
+
+fn option_retry_on_status(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(status_codes, reader)?;
+    Ok(OptionKind::RetryOnStatus(value))
+}
+
+fn option_send_fragment(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::SendFragment(value))
+}
+
 fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
     Ok(OptionKind::Skip(value))
 }
 
-fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = unquoted_template(reader)?;
-    Ok(OptionKind::User(value))
+fn option_speed_limit(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::SpeedLimit(value))
 }
 
-fn option_unix_socket(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = unquoted_template(reader)?;
-    Ok(OptionKind::UnixSocket(value))
+fn option_speed_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::SpeedTime(value))
 }
 
-fn option_variable(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = variable_definition(reader)?;
-    Ok(OptionKind::Variable(value))
+fn option_ssh_private_key(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::SshPrivateKey(value))
 }
 
-fn option_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
+fn option_ssh_public_key(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::SshPublicKey(value))
+}
+
+fn option_stop_on_failure(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
-    Ok(OptionKind::Verbose(value))
+    Ok(OptionKind::StopOnFailure(value))
 }
 
-fn option_very_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
+fn option_tcp_keepalive(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean_option, reader)?;
-    Ok(OptionKind::VeryVerbose(value))
+    Ok(OptionKind::TcpKeepAlive(value))
 }
 
-fn retry(reader: &mut Reader) -> ParseResult<Retry> {
-    let pos = reader.state.pos;
-    let value = nonrecover(integer, reader)?;
-    if value == -1 {
-        Ok(Retry::Infinite)
-    } else if value == 0 {
-        Ok(Retry::None)
-    } else if value > 0 {
-        Ok(Retry::Finite(value as usize))
-    } else {
-        let inner = ParseError::Expecting {
-            value: "Expecting a retry value".to_string(),
-        };
-        Err(Error::new(pos, false, inner))
-    }
+fn option_tcp_keepidle(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::TcpKeepIdle(value))
 }
 
-fn boolean_option(reader: &mut Reader) -> ParseResult<BooleanOption> {
-    let start = reader.state;
-    match boolean(reader) {
-        Ok(v) => Ok(BooleanOption::Literal(v)),
-        Err(_) => {
+fn option_tcp_keepintvl(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::TcpKeepIntvl(value))
+}
+
+fn option_tcp_nodelay(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::TcpNoDelay(value))
+}
+
+fn option_tls_max(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(tls_version, reader)?;
+    Ok(OptionKind::TlsMax(value))
+}
+
+fn option_trace_body(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(trace_mode, reader)?;
+    Ok(OptionKind::TraceBody(value))
+}
+
+fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::User(value))
+}
+
+fn option_unix_socket(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::UnixSocket(value))
+}
+
+fn option_url(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::Url(value))
+}
+
+fn option_user_agent(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = option_template(reader)?;
+    Ok(OptionKind::UserAgent(value))
+}
+
+fn option_variable(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = variable_definition(reader)?;
+    Ok(OptionKind::Variable(value))
+}
+
+fn option_local_variable(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = variable_definition(reader)?;
+    Ok(OptionKind::LocalVariable(value))
+}
+
+fn option_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
+    nonrecover(
+        |p1| {
+            choice(
+                &[
+                    |p2| verbose_level(p2).map(OptionKind::VerboseLevel),
+                    |p2| boolean_option(p2).map(OptionKind::Verbose),
+                ],
+                p1,
+            )
+        },
+        reader,
+    )
+}
+
+fn option_very_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean_option, reader)?;
+    Ok(OptionKind::VeryVerbose(value))
+}
+
+fn retry(reader: &mut Reader) -> ParseResult<Retry> {
+    let pos = reader.state.pos;
+    let value = nonrecover(integer, reader)?;
+    if value == -1 {
+        Ok(Retry::Infinite)
+    } else if value == 0 {
+        Ok(Retry::None)
+    } else if value > 0 {
+        Ok(Retry::Finite(value as usize))
+    } else {
+        let inner = ParseError::Expecting {
+            value: "Expecting a retry value".to_string(),
+        };
+        Err(Error::new(pos, false, inner))
+    }
+}
+
+/// Parses a verbosity level: `0` (not verbose), `1` (verbose) or `2` (very verbose).
+fn verbose_level(reader: &mut Reader) -> ParseResult<u8> {
+    let start = reader.state.pos;
+    let value = natural(reader)?;
+    if value > 2 {
+        let inner = ParseError::Expecting {
+            value: "verbose level 0, 1 or 2".to_string(),
+        };
+        return Err(Error::new(start, false, inner));
+    }
+    Ok(value as u8)
+}
+
+/// Parses a comma-separated list of HTTP status codes (ex: `429,503`).
+fn status_codes(reader: &mut Reader) -> ParseResult<Vec<u32>> {
+    let mut codes = vec![];
+    loop {
+        let pos = reader.state.pos;
+        let code = natural(reader).map_err(|e| {
+            let inner = ParseError::Expecting {
+                value: "HTTP status code".to_string(),
+            };
+            Error::new(e.pos, false, inner)
+        })?;
+        if !(100..=599).contains(&code) {
+            let inner = ParseError::Expecting {
+                value: "HTTP status code".to_string(),
+            };
+            return Err(Error::new(pos, false, inner));
+        }
+        codes.push(code as u32);
+        if try_literal(",", reader).is_err() {
+            break;
+        }
+    }
+    Ok(codes)
+}
+
+/// Parses a size in bytes, with an optional byte unit (`K`, `M`, `G`) or bit unit (`Kbps`,
+/// `Mbps`, `Gbps`).
+fn byte_size(reader: &mut Reader) -> ParseResult<ByteSize> {
+    let value = natural(reader)?;
+    let pos = reader.state.pos;
+    let unit = reader.read_while(|c| c.is_ascii_alphabetic());
+    let unit = match unit.as_str() {
+        "" => None,
+        "K" | "k" => Some(ByteUnit::Kilo),
+        "M" | "m" => Some(ByteUnit::Mega),
+        "G" | "g" => Some(ByteUnit::Giga),
+        "Kbps" | "kbps" => Some(ByteUnit::KiloBit),
+        "Mbps" | "mbps" => Some(ByteUnit::MegaBit),
+        "Gbps" | "gbps" => Some(ByteUnit::GigaBit),
+        _ => {
+            let inner = ParseError::Expecting {
+                value: "K, M, G, Kbps, Mbps or Gbps unit".to_string(),
+            };
+            return Err(Error::new(pos, false, inner));
+        }
+    };
+    Ok(ByteSize { value, unit })
+}
+
+/// Parses a `Content-Length` behaviour: `auto`, `omit` or a number of bytes.
+fn content_length(reader: &mut Reader) -> ParseResult<ContentLength> {
+    let start = reader.state;
+    if try_literal("auto", reader).is_ok() {
+        return Ok(ContentLength::Auto);
+    }
+    if try_literal("omit", reader).is_ok() {
+        return Ok(ContentLength::Omit);
+    }
+    natural(reader).map(ContentLength::Fixed).map_err(|_| {
+        let inner = ParseError::Expecting {
+            value: "auto, omit or integer".to_string(),
+        };
+        Error::new(start.pos, false, inner)
+    })
+}
+
+/// Returns `true` if `value` is an uppercase HTTP method token, like `GET` or `VERSION-CONTROL`.
+fn is_method_token(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c == '-' || c == '_')
+}
+
+/// Checks that a `connect-to` value has the `HOST1:PORT1:HOST2:PORT2` shape, with numeric ports.
+/// As with curl, any part can be empty and IPv6 hosts are enclosed in brackets. Returns the
+/// expected shape if the value is not valid.
+fn check_connect_to(value: &str) -> Result<(), String> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut in_brackets = false;
+    for (index, c) in value.char_indices() {
+        match c {
+            '[' => in_brackets = true,
+            ']' => in_brackets = false,
+            ':' if !in_brackets => {
+                parts.push(&value[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    let [_, port1, _, port2] = parts[..] else {
+        return Err("HOST1:PORT1:HOST2:PORT2".to_string());
+    };
+    let is_port = |port: &str| port.is_empty() || port.parse::<u16>().is_ok();
+    if !is_port(port1) || !is_port(port2) {
+        return Err("HOST1:PORT1:HOST2:PORT2 with numeric ports".to_string());
+    }
+    Ok(())
+}
+
+/// Checks that a `resolve` value has the `HOST:PORT:ADDR[,ADDR]...` shape, with a numeric `PORT`
+/// (or `*` for all ports) and IP addresses `ADDR` (IPv6 addresses being optionally enclosed in brackets). Returns the
+/// expected shape if the value is not valid.
+fn check_resolve(value: &str) -> Result<(), String> {
+    let mut parts = value.splitn(3, ':');
+    let (Some(host), Some(port), Some(addrs)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("HOST:PORT:ADDR".to_string());
+    };
+    if host.is_empty() {
+        return Err("HOST:PORT:ADDR".to_string());
+    }
+    if port != "*" && port.parse::<u16>().is_err() {
+        return Err("HOST:PORT:ADDR with a numeric PORT".to_string());
+    }
+    let is_ip = |addr: &str| {
+        let addr = addr
+            .strip_prefix('[')
+            .and_then(|a| a.strip_suffix(']'))
+            .unwrap_or(addr);
+        addr.parse::<IpAddr>().is_ok()
+    };
+    if !addrs.split(',').all(is_ip) {
+        return Err("HOST:PORT:ADDR with an IP address ADDR".to_string());
+    }
+    Ok(())
+}
+
+/// Checks that a `resolve` removal value has the `HOST:PORT` shape, with a numeric `PORT` (or `*`
+/// for all ports). Returns the expected shape if the value is not valid.
+fn check_remove_resolve(value: &str) -> Result<(), String> {
+    let Some((host, port)) = value.split_once(':') else {
+        return Err("-HOST:PORT".to_string());
+    };
+    if host.is_empty() {
+        return Err("-HOST:PORT".to_string());
+    }
+    if port != "*" && port.parse::<u16>().is_err() {
+        return Err("-HOST:PORT with a numeric PORT".to_string());
+    }
+    Ok(())
+}
+
+/// Parses a certificate or key file type: `PEM`, `DER`, `P12` or `ENG`.
+fn cert_type(reader: &mut Reader) -> ParseResult<CertType> {
+    let start = reader.state;
+    if try_literal("PEM", reader).is_ok() {
+        return Ok(CertType::Pem);
+    }
+    if try_literal("DER", reader).is_ok() {
+        return Ok(CertType::Der);
+    }
+    if try_literal("P12", reader).is_ok() {
+        return Ok(CertType::P12);
+    }
+    if try_literal("ENG", reader).is_ok() {
+        return Ok(CertType::Eng);
+    }
+    let inner = ParseError::Expecting {
+        value: "PEM, DER, P12 or ENG".to_string(),
+    };
+    Err(Error::new(start.pos, false, inner))
+}
+
+/// Parses an HTTP version: `1.0`, `1.1`, `2` or `3`.
+fn http_version(reader: &mut Reader) -> ParseResult<HttpVersion> {
+    let start = reader.state;
+    if try_literal("1.0", reader).is_ok() {
+        return Ok(HttpVersion::Http10);
+    }
+    if try_literal("1.1", reader).is_ok() {
+        return Ok(HttpVersion::Http11);
+    }
+    if try_literal("2", reader).is_ok() {
+        return Ok(HttpVersion::Http2);
+    }
+    if try_literal("3", reader).is_ok() {
+        return Ok(HttpVersion::Http3);
+    }
+    let inner = ParseError::Expecting {
+        value: "1.0, 1.1, 2 or 3".to_string(),
+    };
+    Err(Error::new(start.pos, false, inner))
+}
+
+/// Parses an IP version: `auto`, `4` or `6`.
+fn ip_version(reader: &mut Reader) -> ParseResult<IpVersion> {
+    let start = reader.state;
+    if try_literal("auto", reader).is_ok() {
+        return Ok(IpVersion::Auto);
+    }
+    if try_literal("4", reader).is_ok() {
+        return Ok(IpVersion::V4);
+    }
+    if try_literal("6", reader).is_ok() {
+        return Ok(IpVersion::V6);
+    }
+    let inner = ParseError::Expecting {
+        value: "auto, 4 or 6".to_string(),
+    };
+    Err(Error::new(start.pos, false, inner))
+}
+
+/// Parses a TLS version: `1.0`, `1.1`, `1.2` or `1.3`.
+fn tls_version(reader: &mut Reader) -> ParseResult<TlsVersion> {
+    let start = reader.state;
+    if try_literal("1.0", reader).is_ok() {
+        return Ok(TlsVersion::Tls10);
+    }
+    if try_literal("1.1", reader).is_ok() {
+        return Ok(TlsVersion::Tls11);
+    }
+    if try_literal("1.2", reader).is_ok() {
+        return Ok(TlsVersion::Tls12);
+    }
+    if try_literal("1.3", reader).is_ok() {
+        return Ok(TlsVersion::Tls13);
+    }
+    let inner = ParseError::Expecting {
+        value: "1.0, 1.1, 1.2 or 1.3".to_string(),
+    };
+    Err(Error::new(start.pos, false, inner))
+}
+
+/// Parses the errors triggering a retry: `asserts`, `transport` or `all`.
+fn retry_trigger(reader: &mut Reader) -> ParseResult<RetryTrigger> {
+    let start = reader.state;
+    if try_literal("asserts", reader).is_ok() {
+        return Ok(RetryTrigger::Asserts);
+    }
+    if try_literal("transport", reader).is_ok() {
+        return Ok(RetryTrigger::Transport);
+    }
+    if try_literal("all", reader).is_ok() {
+        return Ok(RetryTrigger::All);
+    }
+    let inner = ParseError::Expecting {
+        value: "asserts, transport or all".to_string(),
+    };
+    Err(Error::new(start.pos, false, inner))
+}
+
+/// Parses a body trace mode: `off`, `ascii` or `hex`.
+fn trace_mode(reader: &mut Reader) -> ParseResult<TraceMode> {
+    let start = reader.state;
+    if try_literal("off", reader).is_ok() {
+        return Ok(TraceMode::Off);
+    }
+    if try_literal("ascii", reader).is_ok() {
+        return Ok(TraceMode::Ascii);
+    }
+    if try_literal("hex", reader).is_ok() {
+        return Ok(TraceMode::Hex);
+    }
+    let inner = ParseError::Expecting {
+        value: "off, ascii or hex".to_string(),
+    };
+    Err(Error::new(start.pos, false, inner))
+}
+
+fn boolean_option(reader: &mut Reader) -> ParseResult<BooleanOption> {
+    let start = reader.state;
+    match boolean(reader) {
+        Ok(v) => Ok(BooleanOption::Literal(v)),
+        Err(_) => {
             reader.state = start;
             let exp = expr::parse(reader).map_err(|e| {
                 let inner = ParseError::Expecting {
@@ -301,6 +1288,47 @@ fn natural_option(reader: &mut Reader) -> ParseResult<NaturalOption> {
     }
 }
 
+fn duration_option(reader: &mut Reader) -> ParseResult<DurationOption> {
+    let start = reader.state;
+    match duration(reader) {
+        Ok(v) => Ok(DurationOption::Literal(v)),
+        Err(e) if !e.recoverable => Err(e),
+        Err(_) => {
+            reader.state = start;
+            let exp = expr::parse(reader).map_err(|e| {
+                let inner = ParseError::Expecting {
+                    value: "integer".to_string(),
+                };
+                Error::new(e.pos, false, inner)
+            })?;
+            Ok(DurationOption::Expression(exp))
+        }
+    }
+}
+
+/// Parses a duration: a number of milliseconds, optionally followed by a unit (`ms`, `s` or `m`).
+fn duration(reader: &mut Reader) -> ParseResult<Duration> {
+    let start = reader.state.pos;
+    let value = natural(reader)?;
+    let unit = if try_literal("ms", reader).is_ok() {
+        Some(DurationUnit::MilliSecond)
+    } else if try_literal("s", reader).is_ok() {
+        Some(DurationUnit::Second)
+    } else if try_literal("m", reader).is_ok() {
+        Some(DurationUnit::Minute)
+    } else {
+        None
+    };
+    let factor = unit.map_or(1, |unit| unit.millis());
+    let Some(millis) = value.checked_mul(factor) else {
+        let inner = ParseError::Expecting {
+            value: "duration".to_string(),
+        };
+        return Err(Error::new(start, false, inner));
+    };
+    Ok(Duration { millis, unit })
+}
+
 fn retry_option(reader: &mut Reader) -> ParseResult<RetryOption> {
     let start = reader.state;
     match retry(reader) {
@@ -345,6 +1373,28 @@ fn variable_name(reader: &mut Reader) -> ParseResult<String> {
 }
 
 fn variable_value(reader: &mut Reader) -> ParseResult<VariableValue> {
+    choice(
+        &[
+            variable_file,
+            variable_env,
+            variable_typed_value,
+            |p1| match option_template(p1) {
+                Ok(value) => Ok(VariableValue::String(value)),
+                Err(e) => Err(e),
+            },
+        ],
+        reader,
+    )
+    .map_err(|e| {
+        let inner = ParseError::Expecting {
+            value: "variable value".to_string(),
+        };
+        Error::new(e.pos, false, inner)
+    })
+}
+
+/// Parses a variable value that can be delimited, i.e. any value but an unquoted string.
+fn variable_typed_value(reader: &mut Reader) -> ParseResult<VariableValue> {
     choice(
         &[
             |p1| match null(p1) {
@@ -355,46 +1405,934 @@ fn variable_value(reader: &mut Reader) -> ParseResult<VariableValue> {
                 Ok(value) => Ok(VariableValue::Bool(value)),
                 Err(e) => Err(e),
             },
-            |p1| match number(p1) {
-                Ok(value) => Ok(VariableValue::Number(value)),
+            |p1| match radix_integer(p1) {
+                Ok(value) => Ok(VariableValue::Number(Number::Integer(value))),
                 Err(e) => Err(e),
             },
-            |p1| match quoted_template(p1) {
-                Ok(value) => Ok(VariableValue::String(value)),
+            |p1| match variable_number(p1) {
+                Ok(value) => Ok(VariableValue::Number(value)),
                 Err(e) => Err(e),
             },
-            |p1| match unquoted_template(p1) {
+            |p1| match quoted_template(p1) {
                 Ok(value) => Ok(VariableValue::String(value)),
                 Err(e) => Err(e),
             },
+            variable_list,
+            variable_object,
         ],
         reader,
     )
-    .map_err(|e| {
+}
+
+/// Parses a number, with an optional exponent (ex: `1e-6`, `2.5E10`).
+///
+/// A number with an exponent is always a float, a number without exponent is parsed by [`number`].
+fn variable_number(reader: &mut Reader) -> ParseResult<Number> {
+    let start = reader.state;
+    let value = number(reader)?;
+    if !matches!(reader.peek(), Some('e' | 'E')) {
+        return Ok(value);
+    }
+    reader.read();
+    if !reader.try_literal("-") {
+        reader.try_literal("+");
+    }
+    let save = reader.state;
+    let exponent_digits = reader.read_while(|c| c.is_ascii_digit());
+    if exponent_digits.is_empty() {
         let inner = ParseError::Expecting {
-            value: "variable value".to_string(),
+            value: "exponent digits".to_string(),
         };
-        Error::new(e.pos, false, inner)
+        return Err(Error::new(save.pos, false, inner));
+    }
+    let encoded = reader.peek_back(start.cursor);
+    match encoded.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(Number::Float(Float { value, encoded })),
+        _ => {
+            let inner = ParseError::Expecting {
+                value: "float".to_string(),
+            };
+            Err(Error::new(start.pos, false, inner))
+        }
+    }
+}
+
+/// Parses an hexadecimal (ex: `0xFF`) or octal (ex: `0o17`) integer.
+///
+/// The error is recoverable without a `0x` or `0o` prefix, so that decimal integers and floats
+/// are parsed by [`number`].
+fn radix_integer(reader: &mut Reader) -> ParseResult<i64> {
+    let (radix, digits_name, is_digit): (u32, &str, fn(&char) -> bool) =
+        if try_literal("0x", reader).is_ok() {
+            (16, "hexadecimal digits", |c| c.is_ascii_hexdigit())
+        } else if try_literal("0o", reader).is_ok() {
+            (8, "octal digits", |c| ('0'..='7').contains(c))
+        } else {
+            let inner = ParseError::Expecting {
+                value: "integer".to_string(),
+            };
+            return Err(Error::new(reader.state.pos, true, inner));
+        };
+    let save = reader.state;
+    let digits = reader.read_while(is_digit);
+    if digits.is_empty() {
+        let inner = ParseError::Expecting {
+            value: digits_name.to_string(),
+        };
+        return Err(Error::new(save.pos, false, inner));
+    }
+    match i64::from_str_radix(&digits, radix) {
+        Ok(value) => Ok(value),
+        Err(_) => {
+            let inner = ParseError::Expecting {
+                value: "integer".to_string(),
+            };
+            Err(Error::new(save.pos, false, inner))
+        }
+    }
+}
+
+/// Parses a bracketed, comma-separated list of variable values (ex: `[1,2,3]`).
+///
+/// Errors are recoverable so that a value like `[abc` is still parsed as an unquoted string.
+fn variable_list(reader: &mut Reader) -> ParseResult<VariableValue> {
+    try_literal("[", reader)?;
+    let mut values = vec![];
+    zero_or_more_spaces(reader)?;
+    if try_literal("]", reader).is_ok() {
+        return Ok(VariableValue::List(values));
+    }
+    loop {
+        let value = variable_typed_value(reader).map_err(|e| e.recoverable())?;
+        values.push(value);
+        zero_or_more_spaces(reader)?;
+        if try_literal("]", reader).is_ok() {
+            break;
+        }
+        try_literal(",", reader)?;
+        zero_or_more_spaces(reader)?;
+    }
+    Ok(VariableValue::List(values))
+}
+
+/// Parses an object of variable values with unquoted keys (ex: `{a:1,b:"x"}`).
+///
+/// The parser only commits once a key has been read, so that `{{name}}` is still parsed as an
+/// unquoted template.
+fn variable_object(reader: &mut Reader) -> ParseResult<VariableValue> {
+    try_literal("{", reader)?;
+    let mut entries = vec![];
+    zero_or_more_spaces(reader)?;
+    if try_literal("}", reader).is_ok() {
+        return Ok(VariableValue::Object(entries));
+    }
+    loop {
+        let key = variable_object_key(reader)?;
+        zero_or_more_spaces(reader)?;
+        literal(":", reader)?;
+        zero_or_more_spaces(reader)?;
+        let value = variable_typed_value(reader).map_err(|e| e.non_recoverable())?;
+        entries.push((key, value));
+        zero_or_more_spaces(reader)?;
+        if try_literal("}", reader).is_ok() {
+            break;
+        }
+        literal(",", reader)?;
+        zero_or_more_spaces(reader)?;
+    }
+    Ok(VariableValue::Object(entries))
+}
+
+/// Parses a variable value read from a file (ex: `file,secrets/token.txt;` or
+/// `file,secrets/token.txt`), with an optional default (ex: `file,secrets/token.txt;:anonymous`).
+///
+/// Without the trailing `;`, the filename runs to the end of the value. Values that don't match
+/// this form (ex: `file,a b`) are still parsed as unquoted strings.
+fn variable_file(reader: &mut Reader) -> ParseResult<VariableValue> {
+    let start = reader.state;
+    variable_file_value(reader).map_err(|e| {
+        reader.state = start;
+        e.recoverable()
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ast::Pos;
+fn variable_file_value(reader: &mut Reader) -> ParseResult<VariableValue> {
+    try_literal("file,", reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let filename = filename::parse(reader)?;
+    if filename.elements.is_empty() {
+        let inner = ParseError::Expecting {
+            value: "filename".to_string(),
+        };
+        return Err(Error::new(reader.state.pos, true, inner));
+    }
+    let save = reader.state;
+    let space1 = zero_or_more_spaces(reader)?;
+    let file = File {
+        space0,
+        filename,
+        space1,
+    };
+    let value = if try_literal(";", reader).is_ok() {
+        let default = variable_default(reader)?;
+        VariableValue::File(file, default)
+    } else {
+        reader.state = save;
+        let space1 = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(save.pos, save.pos),
+        };
+        VariableValue::File(File { space1, ..file }, None)
+    };
+    end_of_value(reader)?;
+    Ok(value)
+}
 
-    #[test]
-    fn test_option_insecure() {
-        let mut reader = Reader::new("insecure: true");
-        let option = parse(&mut reader).unwrap();
-        assert_eq!(
-            option,
-            EntryOption {
-                line_terminators: vec![],
-                space0: Whitespace {
-                    value: String::new(),
-                    source_info: SourceInfo {
-                        start: Pos { line: 1, column: 1 },
+/// Parses a variable value read from the environment (ex: `env,HOME`), with an optional default
+/// (ex: `env,PORT:8080`).
+///
+/// Values that don't match this form (ex: `env,foo bar`) are still parsed as unquoted strings.
+fn variable_env(reader: &mut Reader) -> ParseResult<VariableValue> {
+    let start = reader.state;
+    variable_env_value(reader).map_err(|e| {
+        reader.state = start;
+        e.recoverable()
+    })
+}
+
+fn variable_env_value(reader: &mut Reader) -> ParseResult<VariableValue> {
+    try_literal("env,", reader)?;
+    let start = reader.state;
+    let name = reader.read_while(|c| c.is_ascii_alphanumeric() || *c == '_');
+    if name.is_empty() {
+        let inner = ParseError::Expecting {
+            value: "environment variable name".to_string(),
+        };
+        return Err(Error::new(start.pos, true, inner));
+    }
+    let default = variable_default(reader)?;
+    end_of_value(reader)?;
+    Ok(VariableValue::Env(name, default))
+}
+
+/// Checks that `reader` is at the end of an option value: only spaces are left before a comment,
+/// the end of the line or the end of the file. The reader is left unchanged.
+fn end_of_value(reader: &mut Reader) -> ParseResult<()> {
+    let save = reader.state;
+    zero_or_more_spaces(reader)?;
+    let end = reader.is_eof()
+        || reader.peek_n(1) == "\n"
+        || reader.peek_n(1) == "#"
+        || reader.peek_n(2) == "\r\n";
+    let pos = reader.state.pos;
+    reader.state = save;
+    if end {
+        Ok(())
+    } else {
+        let inner = ParseError::Expecting {
+            value: "end of value".to_string(),
+        };
+        Err(Error::new(pos, true, inner))
+    }
+}
+
+/// Parses the optional `:default` suffix of a variable value read from a file or the environment.
+fn variable_default(reader: &mut Reader) -> ParseResult<Option<Box<VariableValue>>> {
+    if try_literal(":", reader).is_err() {
+        return Ok(None);
+    }
+    let value = variable_value(reader)?;
+    Ok(Some(Box::new(value)))
+}
+
+fn variable_object_key(reader: &mut Reader) -> ParseResult<String> {
+    let start = reader.state;
+    let key = reader.read_while(|c| c.is_alphanumeric() || *c == '_' || *c == '-');
+    if key.is_empty() {
+        let inner = ParseError::Expecting {
+            value: "key".to_string(),
+        };
+        return Err(Error::new(start.pos, true, inner));
+    }
+    Ok(key)
+}
+
+/// Sorts `options` in a canonical order, by option name.
+///
+/// The sort is stable: repeatable options (ex: `variable`, `connect-to`) keep their relative order.
+pub fn sort_options(options: &mut [EntryOption]) {
+    options.sort_by_key(|option| option.kind.name());
+}
+
+/// Returns warnings for the values of `options` referencing variables that are not declared.
+///
+/// A variable is declared if it belongs to `declared`, or if it has been defined by a previous
+/// `variable` option of `options`.
+pub fn validate_all(options: &[EntryOption], declared: &HashSet<String>) -> Vec<Error> {
+    let mut errors = vec![];
+    let mut declared = declared.clone();
+    for option in options {
+        for variable in collect_variable_refs(&option.kind) {
+            if !declared.contains(&variable.name) {
+                let inner = ParseError::UndeclaredVariable {
+                    name: variable.name.clone(),
+                };
+                errors.push(Error::new(variable.source_info.start, true, inner));
+            }
+        }
+        if let OptionKind::Variable(VariableDefinition { name, .. })
+        | OptionKind::LocalVariable(VariableDefinition { name, .. }) = &option.kind
+        {
+            declared.insert(name.clone());
+        }
+    }
+    errors
+}
+
+/// Checks that the literal IP versions of `options` (`ipv4: true`, `ipv6: true` or `ip-version`)
+/// don't conflict, and returns an error on the first conflicting option otherwise.
+pub fn check_ip_version(options: &[EntryOption]) -> ParseResult<()> {
+    let mut requested: Option<(IpVersion, &str)> = None;
+    for option in options {
+        let version = match &option.kind {
+            OptionKind::IpV4(BooleanOption::Literal(true)) => IpVersion::V4,
+            OptionKind::IpV6(BooleanOption::Literal(true)) => IpVersion::V6,
+            OptionKind::IpVersion(version @ (IpVersion::V4 | IpVersion::V6)) => *version,
+            _ => continue,
+        };
+        if let Some((previous, other)) = requested {
+            if previous != version {
+                let inner = ParseError::ConflictingOption {
+                    name: option.kind.name().to_string(),
+                    other: other.to_string(),
+                };
+                return Err(Error::new(option.space0.source_info.end, false, inner));
+            }
+        }
+        requested = Some((version, option.kind.name()));
+    }
+    Ok(())
+}
+
+/// Returns warnings for the options of `options` already defined by a previous option of the same
+/// kind. Options that can be repeated (`connect-to`, `form`, `query`, `remove-resolve`, `resolve`,
+/// `variable` and `variable-local`) are not checked.
+pub fn validate_options(options: &[EntryOption]) -> Vec<Error> {
+    let mut errors = vec![];
+    let mut names = HashSet::new();
+    for option in options {
+        if matches!(
+            option.kind,
+            OptionKind::ConnectTo(_)
+                | OptionKind::Form(_)
+                | OptionKind::Query(_)
+                | OptionKind::RemoveResolve(_)
+                | OptionKind::Resolve(_)
+                | OptionKind::Variable(_)
+                | OptionKind::LocalVariable(_)
+        ) {
+            continue;
+        }
+        let name = option.kind.name();
+        if !names.insert(name) {
+            let inner = ParseError::DuplicateOption {
+                name: name.to_string(),
+            };
+            errors.push(Error::new(option.space0.source_info.end, true, inner));
+        }
+    }
+    errors
+}
+
+/// Returns warnings for the `connect-to` options of `options` shadowed by a later `connect-to`
+/// option with the same `HOST1:PORT1` source. Only literal values are checked.
+pub fn validate_connect_to(options: &[EntryOption]) -> Vec<Error> {
+    let connects_to = options
+        .iter()
+        .filter_map(|option| match &option.kind {
+            OptionKind::ConnectTo(template) if template_variable_refs(template).is_empty() => {
+                let value = template.to_string();
+                let source = connect_to_source(&value).to_string();
+                Some((source, template.source_info.start))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    connects_to
+        .iter()
+        .enumerate()
+        .filter(|(i, (source, _))| connects_to[i + 1..].iter().any(|(s, _)| s == source))
+        .map(|(_, (source, pos))| {
+            let inner = ParseError::ShadowedConnectTo {
+                source: source.clone(),
+            };
+            Error::new(*pos, true, inner)
+        })
+        .collect()
+}
+
+/// Returns the `HOST1:PORT1` source of a `HOST1:PORT1:HOST2:PORT2` connect-to value.
+fn connect_to_source(value: &str) -> &str {
+    match value.match_indices(':').nth(1) {
+        Some((index, _)) => &value[..index],
+        None => value,
+    }
+}
+
+/// Returns the variables referenced by the value of the option `kind`.
+fn collect_variable_refs(kind: &OptionKind) -> Vec<&Variable> {
+    match kind {
+        OptionKind::Accept(value)
+        | OptionKind::AcceptEncoding(value)
+        | OptionKind::AwsRegion(value)
+        | OptionKind::AwsService(value)
+        | OptionKind::AwsSigV4(value)
+        | OptionKind::BodyFile(value)
+        | OptionKind::CaCertificate(value)
+        | OptionKind::ClientCert(value)
+        | OptionKind::ClientKey(value)
+        | OptionKind::ConnectTo(value)
+        | OptionKind::ContentType(value)
+        | OptionKind::DataBinary(value)
+        | OptionKind::Form(value)
+        | OptionKind::Json(value)
+        | OptionKind::KeyPassword(value)
+        | OptionKind::LoginOptions(value)
+        | OptionKind::NetRcFile(value)
+        | OptionKind::NoProxy(value)
+        | OptionKind::Output(value)
+        | OptionKind::Proxy(value)
+        | OptionKind::ProxyCaCert(value)
+        | OptionKind::ProxyUser(value)
+        | OptionKind::Query(value)
+        | OptionKind::Range(value)
+        | OptionKind::Referer(value)
+        | OptionKind::RemoveResolve(value)
+        | OptionKind::RequestMethod(value)
+        | OptionKind::Resolve(value)
+        | OptionKind::SshPrivateKey(value)
+        | OptionKind::SshPublicKey(value)
+        | OptionKind::UnixSocket(value)
+        | OptionKind::Url(value)
+        | OptionKind::User(value)
+        | OptionKind::UserAgent(value) => template_variable_refs(value),
+        OptionKind::AbortOnError(value)
+        | OptionKind::Compressed(value)
+        | OptionKind::ContinueOnError(value)
+        | OptionKind::Crlf(value)
+        | OptionKind::FailWithBody(value)
+        | OptionKind::FollowLocation(value)
+        | OptionKind::FollowLocationTrusted(value)
+        | OptionKind::FreshConnect(value)
+        | OptionKind::GlobOff(value)
+        | OptionKind::HaproxyProtocol(value)
+        | OptionKind::Http10(value)
+        | OptionKind::Http11(value)
+        | OptionKind::Http2(value)
+        | OptionKind::Http3(value)
+        | OptionKind::Insecure(value)
+        | OptionKind::IpV4(value)
+        | OptionKind::IpV6(value)
+        | OptionKind::NetRc(value)
+        | OptionKind::NetRcOptional(value)
+        | OptionKind::NoBuffer(value)
+        | OptionKind::Parallel(value)
+        | OptionKind::PathAsIs(value)
+        | OptionKind::Post301(value)
+        | OptionKind::Post302(value)
+        | OptionKind::Post303(value)
+        | OptionKind::ProxyInsecure(value)
+        | OptionKind::RawResponse(value)
+        | OptionKind::SendFragment(value)
+        | OptionKind::Skip(value)
+        | OptionKind::StopOnFailure(value)
+        | OptionKind::TcpKeepAlive(value)
+        | OptionKind::TcpNoDelay(value)
+        | OptionKind::Verbose(value)
+        | OptionKind::VeryVerbose(value) => match value {
+            BooleanOption::Literal(_) => vec![],
+            BooleanOption::Expression(expr) => vec![&expr.variable],
+        },
+        OptionKind::ConnectTimeout(value)
+        | OptionKind::Delay(value)
+        | OptionKind::MaxTime(value)
+        | OptionKind::RetryDeadline(value)
+        | OptionKind::RetryInterval(value) => match value {
+            DurationOption::Literal(_) => vec![],
+            DurationOption::Expression(expr) => vec![&expr.variable],
+        },
+        OptionKind::DnsCacheTtl(value)
+        | OptionKind::Expect100Timeout(value)
+        | OptionKind::HappyEyeballsTimeout(value)
+        | OptionKind::MaxFileSize(value)
+        | OptionKind::MaxRedirect(value)
+        | OptionKind::Repeat(value)
+        | OptionKind::RetryJitter(value)
+        | OptionKind::SpeedLimit(value)
+        | OptionKind::SpeedTime(value)
+        | OptionKind::TcpKeepIdle(value)
+        | OptionKind::TcpKeepIntvl(value) => match value {
+            NaturalOption::Literal(_) => vec![],
+            NaturalOption::Expression(expr) => vec![&expr.variable],
+        },
+        OptionKind::ConnectRetries(value) | OptionKind::Retry(value) => match value {
+            RetryOption::Literal(_) => vec![],
+            RetryOption::Expression(expr) => vec![&expr.variable],
+        },
+        OptionKind::Variable(VariableDefinition { value, .. })
+        | OptionKind::LocalVariable(VariableDefinition { value, .. }) => variable_value_refs(value),
+        OptionKind::CertType(_)
+        | OptionKind::ContentLength(_)
+        | OptionKind::HttpVersion(_)
+        | OptionKind::IpVersion(_)
+        | OptionKind::KeyType(_)
+        | OptionKind::LimitRate(_)
+        | OptionKind::MaxHeaderSize(_)
+        | OptionKind::RetryOn(_)
+        | OptionKind::RetryOnStatus(_)
+        | OptionKind::TlsMax(_)
+        | OptionKind::TraceBody(_)
+        | OptionKind::VerboseLevel(_) => vec![],
+    }
+}
+
+fn variable_value_refs(value: &VariableValue) -> Vec<&Variable> {
+    match value {
+        VariableValue::String(template) => template_variable_refs(template),
+        VariableValue::List(values) => values.iter().flat_map(variable_value_refs).collect(),
+        VariableValue::Object(entries) => entries
+            .iter()
+            .flat_map(|(_, value)| variable_value_refs(value))
+            .collect(),
+        VariableValue::File(file, default) => {
+            let mut refs = template_variable_refs(&file.filename);
+            if let Some(default) = default {
+                refs.extend(variable_value_refs(default));
+            }
+            refs
+        }
+        VariableValue::Env(_, default) => match default {
+            Some(default) => variable_value_refs(default),
+            None => vec![],
+        },
+        VariableValue::Null | VariableValue::Bool(_) | VariableValue::Number(_) => vec![],
+    }
+}
+
+fn template_variable_refs(template: &Template) -> Vec<&Variable> {
+    template
+        .elements
+        .iter()
+        .filter_map(|element| match element {
+            TemplateElement::String { .. } => None,
+            TemplateElement::Expression(expr) => Some(&expr.variable),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Pos;
+
+    /// Returns the index of the `kind` variant, in the order of [`OPTION_SPECS`] (an option
+    /// name can have several kinds, like `verbose`).
+    ///
+    /// The match is exhaustive on purpose: a new [`OptionKind`] variant doesn't compile until
+    /// it's added here, with a sample in `test_option_specs_kind`.
+    fn option_kind_index(kind: &OptionKind) -> usize {
+        match kind {
+            OptionKind::AbortOnError(_) => 0,
+            OptionKind::Accept(_) => 1,
+            OptionKind::AcceptEncoding(_) => 2,
+            OptionKind::AwsRegion(_) => 3,
+            OptionKind::AwsService(_) => 4,
+            OptionKind::AwsSigV4(_) => 5,
+            OptionKind::BodyFile(_) => 6,
+            OptionKind::CaCertificate(_) => 7,
+            OptionKind::ClientCert(_) => 8,
+            OptionKind::CertType(_) => 9,
+            OptionKind::Compressed(_) => 10,
+            OptionKind::ConnectRetries(_) => 11,
+            OptionKind::ConnectTimeout(_) => 12,
+            OptionKind::ConnectTo(_) => 13,
+            OptionKind::ContentLength(_) => 14,
+            OptionKind::ContentType(_) => 15,
+            OptionKind::ContinueOnError(_) => 16,
+            OptionKind::Crlf(_) => 17,
+            OptionKind::DataBinary(_) => 18,
+            OptionKind::Delay(_) => 19,
+            OptionKind::DnsCacheTtl(_) => 20,
+            OptionKind::Expect100Timeout(_) => 21,
+            OptionKind::FailWithBody(_) => 22,
+            OptionKind::Form(_) => 23,
+            OptionKind::FreshConnect(_) => 24,
+            OptionKind::GlobOff(_) => 25,
+            OptionKind::HappyEyeballsTimeout(_) => 26,
+            OptionKind::HaproxyProtocol(_) => 27,
+            OptionKind::HttpVersion(_) => 28,
+            OptionKind::Http10(_) => 29,
+            OptionKind::Http11(_) => 30,
+            OptionKind::Http2(_) => 31,
+            OptionKind::Http3(_) => 32,
+            OptionKind::Insecure(_) => 33,
+            OptionKind::IpVersion(_) => 34,
+            OptionKind::IpV4(_) => 35,
+            OptionKind::IpV6(_) => 36,
+            OptionKind::Json(_) => 37,
+            OptionKind::ClientKey(_) => 38,
+            OptionKind::KeyPassword(_) => 39,
+            OptionKind::KeyType(_) => 40,
+            OptionKind::LimitRate(_) => 41,
+            OptionKind::FollowLocation(_) => 42,
+            OptionKind::FollowLocationTrusted(_) => 43,
+            OptionKind::LoginOptions(_) => 44,
+            OptionKind::MaxFileSize(_) => 45,
+            OptionKind::MaxHeaderSize(_) => 46,
+            OptionKind::MaxRedirect(_) => 47,
+            OptionKind::MaxTime(_) => 48,
+            OptionKind::NetRc(_) => 49,
+            OptionKind::NetRcFile(_) => 50,
+            OptionKind::NetRcOptional(_) => 51,
+            OptionKind::NoBuffer(_) => 52,
+            OptionKind::NoProxy(_) => 53,
+            OptionKind::Output(_) => 54,
+            OptionKind::Parallel(_) => 55,
+            OptionKind::PathAsIs(_) => 56,
+            OptionKind::Post301(_) => 57,
+            OptionKind::Post302(_) => 58,
+            OptionKind::Post303(_) => 59,
+            OptionKind::Proxy(_) => 60,
+            OptionKind::ProxyCaCert(_) => 61,
+            OptionKind::ProxyInsecure(_) => 62,
+            OptionKind::ProxyUser(_) => 63,
+            OptionKind::Query(_) => 64,
+            OptionKind::Range(_) => 65,
+            OptionKind::RawResponse(_) => 66,
+            OptionKind::Referer(_) => 67,
+            OptionKind::Repeat(_) => 68,
+            OptionKind::RequestMethod(_) => 69,
+            OptionKind::Resolve(_) => 70,
+            OptionKind::RemoveResolve(_) => 71,
+            OptionKind::Retry(_) => 72,
+            OptionKind::RetryDeadline(_) => 73,
+            OptionKind::RetryInterval(_) => 74,
+            OptionKind::RetryJitter(_) => 75,
+            OptionKind::RetryOn(_) => 76,
+            OptionKind::RetryOnStatus(_) => 77,
+            OptionKind::SendFragment(_) => 78,
+            OptionKind::Skip(_) => 79,
+            OptionKind::SpeedLimit(_) => 80,
+            OptionKind::SpeedTime(_) => 81,
+            OptionKind::SshPrivateKey(_) => 82,
+            OptionKind::SshPublicKey(_) => 83,
+            OptionKind::StopOnFailure(_) => 84,
+            OptionKind::TcpKeepAlive(_) => 85,
+            OptionKind::TcpKeepIdle(_) => 86,
+            OptionKind::TcpKeepIntvl(_) => 87,
+            OptionKind::TcpNoDelay(_) => 88,
+            OptionKind::TlsMax(_) => 89,
+            OptionKind::TraceBody(_) => 90,
+            OptionKind::UnixSocket(_) => 91,
+            OptionKind::Url(_) => 92,
+            OptionKind::User(_) => 93,
+            OptionKind::UserAgent(_) => 94,
+            OptionKind::Variable(_) => 95,
+            OptionKind::LocalVariable(_) => 96,
+            OptionKind::Verbose(_) => 97,
+            OptionKind::VerboseLevel(_) => 98,
+            OptionKind::VeryVerbose(_) => 99,
+        }
+    }
+
+    #[test]
+    fn test_option_specs_are_sorted() {
+        for specs in OPTION_SPECS.windows(2) {
+            assert!(
+                specs[0].name < specs[1].name,
+                "{} must be after {}",
+                specs[0].name,
+                specs[1].name
+            );
+        }
+    }
+
+    #[test]
+    fn test_supported_options() {
+        // `option_kind_index` has one arm per option kind, `resolve` and `verbose` having two
+        // kinds each.
+        let kinds = option_kind_index(&OptionKind::VeryVerbose(BooleanOption::Literal(true))) + 1;
+        assert_eq!(supported_options().len(), kinds - 2);
+
+        let insecure = supported_options()
+            .iter()
+            .find(|spec| spec.name == "insecure")
+            .unwrap();
+        assert_eq!(insecure.value_type, OptionValueType::Bool);
+        assert!(!insecure.is_secret());
+        let user = supported_options()
+            .iter()
+            .find(|spec| spec.name == "user")
+            .unwrap();
+        assert_eq!(user.value_type, OptionValueType::Template);
+        assert!(user.is_secret());
+    }
+
+    #[test]
+    fn test_option_specs_kind() {
+        // One sample per option kind: each parser must produce an option kind of the same name.
+        let samples = [
+            "abort-on-error: true",
+            "accept: application/json",
+            "accept-encoding: gzip",
+            "aws-region: eu-central-1",
+            "aws-service: sts",
+            "aws-sigv4: aws:amz:eu-central-1:sts",
+            "body-file: payload.json",
+            "cacert: cacertfile",
+            "cert: certfile",
+            "cert-type: DER",
+            "compressed: false",
+            "connect-retries: 3",
+            "connect-timeout: 5s",
+            "connect-to: example.com:443:example.net:8443",
+            "content-length: omit",
+            "content-type: application/json",
+            "continue-on-error: true",
+            "crlf: true",
+            "data-binary: @data.bin",
+            "delay: 1000",
+            "dns-cache-ttl: 60",
+            "expect-100-timeout: 1000",
+            "fail-with-body: true",
+            "form: field=value",
+            "fresh-connect: true",
+            "globoff: true",
+            "happy-eyeballs-timeout: 200",
+            "haproxy-protocol: true",
+            "http-version: 2",
+            "http1.0: false",
+            "http1.1: false",
+            "http2: false",
+            "http3: false",
+            "insecure: false",
+            "ip-version: auto",
+            "ipv4: false",
+            "ipv6: false",
+            "json: {\"name\":\"Bob\"}",
+            "key: keyfile",
+            "key-password: secret",
+            "key-type: PEM",
+            "limit-rate: 1Mbps",
+            "location: false",
+            "location-trusted: false",
+            "login-options: AUTH=PLAIN",
+            "max-filesize: 1048576",
+            "max-header-size: 64K",
+            "max-redirs: 10",
+            "max-time: 1m",
+            "netrc: false",
+            "netrc-file: netrcfile",
+            "netrc-optional: false",
+            "no-buffer: true",
+            "noproxy: localhost,.internal",
+            "output: output.txt",
+            "parallel: true",
+            "path-as-is: false",
+            "post301: true",
+            "post302: true",
+            "post303: true",
+            "proxy: http://proxy.example",
+            "proxy-cacert: proxy_ca.pem",
+            "proxy-insecure: true",
+            "proxy-user: bob:secret",
+            "query: page=2",
+            "range: 0-1023",
+            "raw: true",
+            "referer: http://example.com/page",
+            "repeat: 3",
+            "request: PROPFIND",
+            "resolve: example.com:443:127.0.0.1",
+            "resolve: -example.com:443",
+            "retry: 0",
+            "retry-deadline: 30000",
+            "retry-interval: 1000",
+            "retry-jitter: 20",
+            "retry-on: asserts",
+            "retry-on-status: 429,503",
+            "send-fragment: false",
+            "skip: false",
+            "speed-limit: 1024",
+            "speed-time: 30",
+            "ssh-private-key: id_rsa",
+            "ssh-public-key: id_rsa.pub",
+            "stop-on-failure: false",
+            "tcp-keepalive: true",
+            "tcp-keepidle: 60",
+            "tcp-keepintvl: 30",
+            "tcp-nodelay: true",
+            "tls-max: 1.2",
+            "trace-body: hex",
+            "unix-socket: build/unix_socket.sock",
+            "url: https://example.org/v2/items",
+            "user: bob:secret",
+            "user-agent: hurl/1.0",
+            "variable: user=null",
+            "variable-local: id=1",
+            "verbose: false",
+            "verbose: 2",
+            "very-verbose: false",
+        ];
+        let names = OPTION_SPECS
+            .iter()
+            .map(|spec| spec.name)
+            .collect::<HashSet<_>>();
+        let mut parsed = HashSet::new();
+        for (index, sample) in samples.iter().enumerate() {
+            let option = parse(&mut Reader::new(sample)).unwrap();
+            assert_eq!(option_kind_index(&option.kind), index, "{sample}");
+            assert!(names.contains(option.kind.name()), "{sample}");
+            parsed.insert(option.kind.name());
+        }
+        assert_eq!(parsed, names);
+    }
+
+    /// Parses `input` as an option and returns its kind.
+    fn option_kind(input: &str) -> OptionKind {
+        parse(&mut Reader::new(input)).unwrap().kind
+    }
+
+    /// Parses `input` as an invalid option and checks its non recoverable error.
+    fn assert_option_error(input: &str, column: usize, inner: ParseError) {
+        let error = parse(&mut Reader::new(input)).err().unwrap();
+        assert!(!error.recoverable, "{input}");
+        assert_eq!(error.pos, Pos::new(1, column), "{input}");
+        assert_eq!(error.inner, inner, "{input}");
+    }
+
+    fn expecting(value: &str) -> ParseError {
+        ParseError::Expecting {
+            value: value.to_string(),
+        }
+    }
+
+    /// Returns the names of the options with a value of type `value_type`.
+    fn option_names_of(value_type: OptionValueType) -> Vec<&'static str> {
+        OPTION_SPECS
+            .iter()
+            .filter(|spec| spec.value_type == value_type)
+            .map(|spec| spec.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_boolean_options() {
+        for name in option_names_of(OptionValueType::Bool) {
+            for value in ["true", "false"] {
+                let kind = option_kind(&format!("{name}: {value}"));
+                assert_eq!(kind.value_as_str(), value, "{name}");
+                assert!(collect_variable_refs(&kind).is_empty(), "{name}");
+            }
+            let input = format!("{name}: 1");
+            assert_option_error(&input, name.len() + 3, expecting("true|false"));
+        }
+    }
+
+    #[test]
+    fn test_natural_options() {
+        for name in option_names_of(OptionValueType::Natural) {
+            for value in ["0", "60"] {
+                let kind = option_kind(&format!("{name}: {value}"));
+                assert_eq!(kind.value_as_str(), value, "{name}");
+                assert!(collect_variable_refs(&kind).is_empty(), "{name}");
+            }
+            let input = format!("{name}: -1");
+            assert_option_error(&input, name.len() + 3, expecting("integer"));
+        }
+    }
+
+    #[test]
+    fn test_duration_options() {
+        // Suffixed and bare forms of each duration option are normalized to the same value.
+        for name in option_names_of(OptionValueType::Duration) {
+            for (value, millis) in [
+                ("2s", 2_000),
+                ("2000ms", 2_000),
+                ("2000", 2_000),
+                ("1m", 60_000),
+                ("60000", 60_000),
+            ] {
+                let input = format!("{name}: {value}");
+                let kind = option_kind(&input);
+                let duration = match &kind {
+                    OptionKind::ConnectTimeout(DurationOption::Literal(duration))
+                    | OptionKind::Delay(DurationOption::Literal(duration))
+                    | OptionKind::MaxTime(DurationOption::Literal(duration))
+                    | OptionKind::RetryDeadline(DurationOption::Literal(duration))
+                    | OptionKind::RetryInterval(DurationOption::Literal(duration)) => *duration,
+                    kind => panic!("unexpected option {kind:?}"),
+                };
+                assert_eq!(duration.millis, millis, "{input}");
+                // The duration is written back with its unit.
+                assert_eq!(kind.to_string(), input);
+            }
+            let input = format!("{name}: -1");
+            assert_option_error(&input, name.len() + 3, expecting("integer"));
+        }
+    }
+
+    #[test]
+    fn test_duration_option_error() {
+        let mut reader = Reader::new("delay: 2h");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 9));
+
+        assert_option_error("delay: 18446744073709551615m", 8, expecting("duration"));
+    }
+
+    #[test]
+    fn test_templated_options() {
+        // Only the options with a specific syntax, like `retry` or `variable`, are not templates.
+        let specs = OPTION_SPECS
+            .iter()
+            .filter(|spec| spec.value_type != OptionValueType::Custom);
+        for spec in specs {
+            let name = spec.name;
+            // A data-binary filename is prefixed with `@`.
+            let prefix = if name == "data-binary" { "@" } else { "" };
+            let kind = option_kind(&format!("{name}: {prefix}{{{{value}}}}"));
+            let variables = collect_variable_refs(&kind)
+                .into_iter()
+                .map(|variable| variable.name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(variables, ["value"], "{name}");
+        }
+    }
+
+    #[test]
+    fn test_option_retry_jitter() {
+        for value in ["0", "25", "100"] {
+            let kind = option_kind(&format!("retry-jitter: {value}"));
+            assert_eq!(kind.value_as_str(), value);
+        }
+        assert_option_error(
+            "retry-jitter: 101",
+            15,
+            expecting("percentage between 0 and 100"),
+        );
+    }
+
+    #[test]
+    fn test_option_insecure() {
+        let kind = option_kind("insecure: true");
+        assert_eq!(
+            option,
+            EntryOption {
+                line_terminators: vec![],
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo {
+                        start: Pos { line: 1, column: 1 },
                         end: Pos { line: 1, column: 1 },
                     },
                 },
@@ -452,6 +2390,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_comment() {
+        let mut reader = Reader::new("insecure: true # only in dev\n");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.comment(),
+            Some(&Comment {
+                value: " only in dev".to_string(),
+                source_info: SourceInfo::new(Pos::new(1, 17), Pos::new(1, 29)),
+            })
+        );
+
+        let mut reader = Reader::new("insecure: true\n");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(option.comment(), None);
+    }
+
+    #[test]
+    fn test_option_invalid_name() {
+        let mut reader = Reader::new("verbsoe: true");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::InvalidOption {
+                name: "verbsoe".to_string(),
+                suggestion: Some("verbose".to_string()),
+            }
+        );
+        assert!(crate::error::Error::fixme(&error).contains("verbose"));
+    }
+
+    #[test]
+    fn test_option_invalid_name_suggestion() {
+        let mut reader = Reader::new("insecur: true");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::InvalidOption {
+                name: "insecur".to_string(),
+                suggestion: Some("insecure".to_string()),
+            }
+        );
+
+        let mut reader = Reader::new("xyzzy: true");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::InvalidOption {
+                name: "xyzzy".to_string(),
+                suggestion: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_invalid_name_is_surfaced() {
+        let mut reader = Reader::new("insecur: true");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 1));
+        assert_eq!(
+            crate::error::Error::fixme(&error),
+            "the option name <insecur> is not valid. Did you mean insecure?"
+        );
+    }
+
     #[test]
     fn test_option_insecure_error() {
         let mut reader = Reader::new("insecure: error");
@@ -461,8 +2465,7 @@ mod tests {
 
     #[test]
     fn test_option_cacert() {
-        let mut reader = Reader::new("cacert: /home/foo/cert.pem");
-        let option = parse(&mut reader).unwrap();
+        let kind = option_kind("cacert: /home/foo/cert.pem");
         assert_eq!(
             option,
             EntryOption {
@@ -535,6 +2538,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_cacert_quoted() {
+        let kind = option_kind("cacert: \"/home/my certs/ca.pem\"\n");
+        let OptionKind::CaCertificate(filename) = &kind else {
+            panic!("cacert option expected");
+        };
+        assert_eq!(filename.to_string(), "/home/my certs/ca.pem");
+        assert_eq!(kind.to_string(), "cacert: \"/home/my certs/ca.pem\"");
+    }
+
     #[test]
     fn test_option_cacert_error() {
         let mut reader = Reader::new("cacert: ###");
@@ -543,91 +2556,1507 @@ mod tests {
     }
 
     #[test]
-    fn test_option_retry_error() {
-        let mut reader = Reader::new("retry: ###");
-        let error = parse(&mut reader).err().unwrap();
-        assert!(!error.recoverable);
-        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+    fn test_option_data_binary() {
         assert_eq!(
-            error.inner,
-            ParseError::Expecting {
-                value: "integer".to_string()
-            }
+            option_kind("data-binary: @payload.bin"),
+            OptionKind::DataBinary(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "payload.bin".to_string(),
+                    encoded: "payload.bin".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 26)),
+            })
         );
     }
 
     #[test]
-    fn test_variable_definition() {
-        let mut reader = Reader::new("a=1");
-        assert_eq!(
-            variable_definition(&mut reader).unwrap(),
-            VariableDefinition {
-                name: "a".to_string(),
-                space0: Whitespace {
-                    value: String::new(),
-                    source_info: SourceInfo {
-                        start: Pos { line: 1, column: 2 },
-                        end: Pos { line: 1, column: 2 },
-                    },
-                },
-                space1: Whitespace {
-                    value: String::new(),
-                    source_info: SourceInfo {
-                        start: Pos { line: 1, column: 3 },
-                        end: Pos { line: 1, column: 3 },
-                    },
-                },
-                value: VariableValue::Number(Number::Integer(1)),
-            }
+    fn test_option_data_binary_error() {
+        assert_option_error(
+            "data-binary: payload.bin",
+            14,
+            ParseError::DataBinaryFilename,
         );
     }
 
     #[test]
-    fn test_variable_value() {
-        let mut reader = Reader::new("null");
-        assert_eq!(variable_value(&mut reader).unwrap(), VariableValue::Null);
+    fn test_option_noproxy() {
+        assert_eq!(
+            option_kind("noproxy: localhost,.internal"),
+            OptionKind::NoProxy(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "localhost,.internal".to_string(),
+                    encoded: "localhost,.internal".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 29)),
+            })
+        );
 
-        let mut reader = Reader::new("true");
+        let kind = option_kind("noproxy: \"localhost,.internal\"");
         assert_eq!(
-            variable_value(&mut reader).unwrap(),
-            VariableValue::Bool(true)
+            kind,
+            OptionKind::NoProxy(Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: "localhost,.internal".to_string(),
+                    encoded: "localhost,.internal".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 31)),
+            })
         );
+        assert_eq!(kind.value_as_str(), "localhost,.internal");
 
-        let mut reader = Reader::new("1");
         assert_eq!(
-            variable_value(&mut reader).unwrap(),
-            VariableValue::Number(Number::Integer(1))
+            option_kind("noproxy: localhost,{{internal_domain}}"),
+            OptionKind::NoProxy(Template {
+                delimiter: None,
+                elements: vec![
+                    TemplateElement::String {
+                        value: "localhost,".to_string(),
+                        encoded: "localhost,".to_string(),
+                    },
+                    TemplateElement::Expression(Expr {
+                        space0: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 22)),
+                        },
+                        variable: Variable {
+                            name: "internal_domain".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 37)),
+                        },
+                        space1: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(Pos::new(1, 37), Pos::new(1, 37)),
+                        },
+                    })
+                ],
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 39)),
+            })
         );
+    }
 
-        let mut reader = Reader::new("toto");
+    #[test]
+    fn test_option_query() {
         assert_eq!(
-            variable_value(&mut reader).unwrap(),
-            VariableValue::String(Template {
+            option_kind("query: page=2"),
+            OptionKind::Query(Template {
                 delimiter: None,
                 elements: vec![TemplateElement::String {
-                    value: "toto".to_string(),
-                    encoded: "toto".to_string(),
+                    value: "page=2".to_string(),
+                    encoded: "page=2".to_string(),
                 }],
-                source_info: SourceInfo {
-                    start: Pos { line: 1, column: 1 },
-                    end: Pos { line: 1, column: 5 },
-                },
+                source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 14)),
             })
         );
-        let mut reader = Reader::new("\"123\"");
+
+        let kind = option_kind("query: \"q=hello world\"");
         assert_eq!(
-            variable_value(&mut reader).unwrap(),
-            VariableValue::String(Template {
+            kind,
+            OptionKind::Query(Template {
                 delimiter: Some('"'),
                 elements: vec![TemplateElement::String {
-                    value: "123".to_string(),
-                    encoded: "123".to_string(),
+                    value: "q=hello world".to_string(),
+                    encoded: "q=hello world".to_string(),
                 }],
-                source_info: SourceInfo {
-                    start: Pos { line: 1, column: 1 },
-                    end: Pos { line: 1, column: 6 },
-                },
+                source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 23)),
             })
         );
+        assert_eq!(kind.value_as_str(), "q=hello world");
+    }
+
+    #[test]
+    fn test_option_connect_to() {
+        let kind = option_kind("connect-to: example.com:443:example.net:8443");
+        assert!(matches!(kind, OptionKind::ConnectTo(_)));
+        assert_eq!(kind.value_as_str(), "example.com:443:example.net:8443");
+
+        assert!(matches!(
+            option_kind("connect-to: ::[::1]:8080"),
+            OptionKind::ConnectTo(_)
+        ));
+
+        // Templated values are not checked.
+        assert!(matches!(
+            option_kind("connect-to: {{source}}:example.net:8443"),
+            OptionKind::ConnectTo(_)
+        ));
+    }
+
+    #[test]
+    fn test_option_connect_to_error() {
+        assert_option_error(
+            "connect-to: example.com:443",
+            13,
+            expecting("HOST1:PORT1:HOST2:PORT2"),
+        );
+
+        let mut reader = Reader::new("connect-to: example.com:https:example.net:8443");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "HOST1:PORT1:HOST2:PORT2 with numeric ports".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_content_length() {
+        assert_eq!(
+            option_kind("content-length: auto"),
+            OptionKind::ContentLength(ContentLength::Auto)
+        );
+
+        assert_eq!(
+            option_kind("content-length: omit"),
+            OptionKind::ContentLength(ContentLength::Omit)
+        );
+
+        assert_eq!(
+            option_kind("content-length: 512"),
+            OptionKind::ContentLength(ContentLength::Fixed(512))
+        );
+    }
+
+    #[test]
+    fn test_option_content_length_error() {
+        assert_option_error(
+            "content-length: none",
+            17,
+            expecting("auto, omit or integer"),
+        );
+    }
+
+    #[test]
+    fn test_option_cert_type() {
+        let types = [
+            ("cert-type: PEM", CertType::Pem),
+            ("cert-type: DER", CertType::Der),
+            ("cert-type: P12", CertType::P12),
+            ("cert-type: ENG", CertType::Eng),
+        ];
+        for (text, cert_type) in types {
+            let kind = option_kind(text);
+            assert_eq!(kind, OptionKind::CertType(cert_type));
+            assert_eq!(kind.value_as_str(), text["cert-type: ".len()..]);
+        }
+    }
+
+    #[test]
+    fn test_option_cert_type_error() {
+        assert_option_error("cert-type: JKS", 12, expecting("PEM, DER, P12 or ENG"));
+    }
+
+    #[test]
+    fn test_option_key_type() {
+        let types = [
+            ("key-type: PEM", CertType::Pem),
+            ("key-type: DER", CertType::Der),
+            ("key-type: P12", CertType::P12),
+            ("key-type: ENG", CertType::Eng),
+        ];
+        for (text, key_type) in types {
+            let kind = option_kind(text);
+            assert_eq!(kind, OptionKind::KeyType(key_type));
+            assert_eq!(kind.value_as_str(), text["key-type: ".len()..]);
+        }
+    }
+
+    #[test]
+    fn test_option_key_type_error() {
+        let mut reader = Reader::new("key-type: pem");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 11
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_http_version() {
+        let versions = [
+            ("http-version: 1.0", HttpVersion::Http10),
+            ("http-version: 1.1", HttpVersion::Http11),
+            ("http-version: 2", HttpVersion::Http2),
+            ("http-version: 3", HttpVersion::Http3),
+        ];
+        for (text, version) in versions {
+            let kind = option_kind(text);
+            assert_eq!(kind, OptionKind::HttpVersion(version));
+            assert_eq!(kind.value_as_str(), text["http-version: ".len()..]);
+        }
+    }
+
+    #[test]
+    fn test_option_http_version_error() {
+        assert_option_error("http-version: 4", 15, expecting("1.0, 1.1, 2 or 3"));
+    }
+
+    #[test]
+    fn test_option_ip_version() {
+        let versions = [
+            ("ip-version: auto", IpVersion::Auto),
+            ("ip-version: 4", IpVersion::V4),
+            ("ip-version: 6", IpVersion::V6),
+        ];
+        for (text, version) in versions {
+            let kind = option_kind(text);
+            assert_eq!(kind, OptionKind::IpVersion(version));
+        }
+    }
+
+    #[test]
+    fn test_option_ip_version_error() {
+        assert_option_error("ip-version: 5", 13, expecting("auto, 4 or 6"));
+    }
+
+    #[test]
+    fn test_option_tls_max() {
+        let versions = [
+            ("tls-max: 1.0", TlsVersion::Tls10),
+            ("tls-max: 1.1", TlsVersion::Tls11),
+            ("tls-max: 1.2", TlsVersion::Tls12),
+            ("tls-max: 1.3", TlsVersion::Tls13),
+        ];
+        for (text, version) in versions {
+            let kind = option_kind(text);
+            assert_eq!(kind, OptionKind::TlsMax(version));
+            assert_eq!(kind.value_as_str(), text["tls-max: ".len()..]);
+        }
+    }
+
+    #[test]
+    fn test_option_tls_max_error() {
+        assert_option_error("tls-max: sslv3", 10, expecting("1.0, 1.1, 1.2 or 1.3"));
+    }
+
+    #[test]
+    fn test_option_trace_body() {
+        assert_eq!(
+            option_kind("trace-body: off"),
+            OptionKind::TraceBody(TraceMode::Off)
+        );
+
+        assert_eq!(
+            option_kind("trace-body: ascii"),
+            OptionKind::TraceBody(TraceMode::Ascii)
+        );
+
+        assert_eq!(
+            option_kind("trace-body: hex"),
+            OptionKind::TraceBody(TraceMode::Hex)
+        );
+    }
+
+    #[test]
+    fn test_option_trace_body_error() {
+        assert_option_error("trace-body: binary", 13, expecting("off, ascii or hex"));
+    }
+
+    #[test]
+    fn test_option_connect_retries() {
+        assert_eq!(
+            option_kind("connect-retries: -1"),
+            OptionKind::ConnectRetries(RetryOption::Literal(Retry::Infinite))
+        );
+
+        assert_eq!(
+            option_kind("connect-retries: 0"),
+            OptionKind::ConnectRetries(RetryOption::Literal(Retry::None))
+        );
+
+        assert_eq!(
+            option_kind("connect-retries: 3"),
+            OptionKind::ConnectRetries(RetryOption::Literal(Retry::Finite(3)))
+        );
+    }
+
+    #[test]
+    fn test_option_request_method() {
+        assert_eq!(
+            option_kind("request: PROPFIND"),
+            OptionKind::RequestMethod(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "PROPFIND".to_string(),
+                    encoded: "PROPFIND".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 18)),
+            })
+        );
+
+        let kind = option_kind("request: VERSION-CONTROL");
+        assert_eq!(kind.value_as_str(), "VERSION-CONTROL");
+
+        assert_eq!(
+            option_kind("request: {{method}}"),
+            OptionKind::RequestMethod(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::Expression(Expr {
+                    space0: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 12)),
+                    },
+                    variable: Variable {
+                        name: "method".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 18)),
+                    },
+                    space1: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(1, 18), Pos::new(1, 18)),
+                    },
+                })],
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 20)),
+            })
+        );
+    }
+
+    #[test]
+    fn test_option_request_method_error() {
+        assert_option_error("request: propfind", 10, expecting("uppercase HTTP method"));
+
+        let mut reader = Reader::new("request: GET /index.html");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "uppercase HTTP method".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_limit_rate() {
+        assert_eq!(
+            option_kind("limit-rate: 1Mbps"),
+            OptionKind::LimitRate(ByteSize {
+                value: 1,
+                unit: Some(ByteUnit::MegaBit)
+            })
+        );
+
+        assert_eq!(
+            option_kind("limit-rate: 100K"),
+            OptionKind::LimitRate(ByteSize {
+                value: 100,
+                unit: Some(ByteUnit::Kilo)
+            })
+        );
+    }
+
+    #[test]
+    fn test_option_limit_rate_error() {
+        assert_option_error(
+            "limit-rate: 1Xbps",
+            14,
+            expecting("K, M, G, Kbps, Mbps or Gbps unit"),
+        );
+    }
+
+    #[test]
+    fn test_option_max_header_size() {
+        assert_eq!(
+            option_kind("max-header-size: 65536"),
+            OptionKind::MaxHeaderSize(ByteSize {
+                value: 65536,
+                unit: None
+            })
+        );
+
+        assert_eq!(
+            option_kind("max-header-size: 64K"),
+            OptionKind::MaxHeaderSize(ByteSize {
+                value: 64,
+                unit: Some(ByteUnit::Kilo)
+            })
+        );
+    }
+
+    #[test]
+    fn test_option_max_header_size_error() {
+        let mut reader = Reader::new("max-header-size: 64KB");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 20
+            }
+        );
+    }
+
+    #[test]
+    fn test_byte_size() {
+        let mut reader = Reader::new("1024");
+        let size = byte_size(&mut reader).unwrap();
+        assert_eq!(size.bytes(), 1024);
+
+        let mut reader = Reader::new("100K");
+        let size = byte_size(&mut reader).unwrap();
+        assert_eq!(size.bytes(), 102_400);
+
+        let mut reader = Reader::new("1Mbps");
+        let size = byte_size(&mut reader).unwrap();
+        assert_eq!(size.bytes(), 125_000);
+
+        let mut reader = Reader::new("2G");
+        let size = byte_size(&mut reader).unwrap();
+        assert_eq!(size.bytes(), 2 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_option_resolve() {
+        let kind = option_kind("resolve: example.com:443:127.0.0.1");
+        assert!(matches!(kind, OptionKind::Resolve(_)));
+        assert_eq!(kind.value_as_str(), "example.com:443:127.0.0.1");
+
+        assert!(matches!(
+            option_kind("resolve: example.com:443:[::1],127.0.0.1"),
+            OptionKind::Resolve(_)
+        ));
+
+        // A wildcard port applies to all ports.
+        let kind = option_kind("resolve: example.com:*:127.0.0.1");
+        assert!(matches!(kind, OptionKind::Resolve(_)));
+        assert_eq!(kind.value_as_str(), "example.com:*:127.0.0.1");
+
+        // Templated values are not checked.
+        assert!(matches!(
+            option_kind("resolve: {{host}}:{{port}}:127.0.0.1"),
+            OptionKind::Resolve(_)
+        ));
+    }
+
+    #[test]
+    fn test_option_line_continuation() {
+        let mut reader = Reader::new("resolve: example.com:443:\\\n    127.0.0.1\nGET");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(option.kind.value_as_str(), "example.com:443:127.0.0.1");
+        let OptionKind::Resolve(value) = option.kind else {
+            panic!("expecting a resolve option");
+        };
+        assert_eq!(
+            value,
+            Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "example.com:443:127.0.0.1".to_string(),
+                    encoded: "example.com:443:\\\n    127.0.0.1".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(2, 14)),
+            }
+        );
+        assert_eq!(
+            option.line_terminator0.newline.source_info.start,
+            Pos::new(2, 14)
+        );
+        assert_eq!(reader.state.pos, Pos::new(3, 1));
+
+        // Spaces before the continuation are kept, and templates can span several lines.
+        let kind = option_kind("user-agent: {{name}} \\\r\n  v1.0");
+        let OptionKind::UserAgent(value) = kind else {
+            panic!("expecting a user-agent option");
+        };
+        assert_eq!(value.to_string(), "{{name}} v1.0");
+        assert_eq!(value.source_info.end, Pos::new(2, 7));
+    }
+
+    #[test]
+    fn test_option_remove_resolve() {
+        let kind = option_kind("resolve: -example.com:443");
+        assert_eq!(
+            kind,
+            OptionKind::RemoveResolve(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "example.com:443".to_string(),
+                    encoded: "example.com:443".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 26)),
+            })
+        );
+        assert_eq!(kind.name(), "resolve");
+        assert_eq!(kind.value_as_str(), "-example.com:443");
+
+        assert!(matches!(
+            option_kind("resolve: -example.com:*"),
+            OptionKind::RemoveResolve(_)
+        ));
+    }
+
+    #[test]
+    fn test_option_remove_resolve_error() {
+        assert_option_error(
+            "resolve: -example.com:443:127.0.0.1",
+            11,
+            expecting("-HOST:PORT with a numeric PORT"),
+        );
+    }
+
+    #[test]
+    fn test_option_resolve_error() {
+        assert_option_error(
+            "resolve: example.com:https:127.0.0.1",
+            10,
+            expecting("HOST:PORT:ADDR with a numeric PORT"),
+        );
+
+        let mut reader = Reader::new("resolve: example.com:**:127.0.0.1");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "HOST:PORT:ADDR with a numeric PORT".to_string()
+            }
+        );
+
+        let mut reader = Reader::new("resolve: example.com:443:localhost");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "HOST:PORT:ADDR with an IP address ADDR".to_string()
+            }
+        );
+
+        let mut reader = Reader::new("resolve: example.com");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "HOST:PORT:ADDR".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_retry() {
+        assert_eq!(
+            option_kind("retry: -1"),
+            OptionKind::Retry(RetryOption::Literal(Retry::Infinite))
+        );
+
+        assert_eq!(
+            option_kind("retry: 0"),
+            OptionKind::Retry(RetryOption::Literal(Retry::None))
+        );
+
+        assert_eq!(
+            option_kind("retry: 5"),
+            OptionKind::Retry(RetryOption::Literal(Retry::Finite(5)))
+        );
+
+        assert_eq!(
+            option_kind("retry: {{ retry_count }}"),
+            OptionKind::Retry(RetryOption::Expression(Expr {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                variable: Variable {
+                    name: "retry_count".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 22)),
+                },
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 23)),
+                },
+            }))
+        );
+    }
+
+    #[test]
+    fn test_option_retry_error() {
+        assert_option_error("retry: ###", 8, expecting("integer"));
+    }
+
+    #[test]
+    fn test_option_retry_on() {
+        assert_eq!(
+            option_kind("retry-on: asserts"),
+            OptionKind::RetryOn(RetryTrigger::Asserts)
+        );
+
+        assert_eq!(
+            option_kind("retry-on: transport"),
+            OptionKind::RetryOn(RetryTrigger::Transport)
+        );
+
+        assert_eq!(
+            option_kind("retry-on: all"),
+            OptionKind::RetryOn(RetryTrigger::All)
+        );
+    }
+
+    #[test]
+    fn test_option_retry_on_error() {
+        assert_option_error(
+            "retry-on: status",
+            11,
+            expecting("asserts, transport or all"),
+        );
+    }
+
+    #[test]
+    fn test_option_retry_on_status() {
+        assert_eq!(
+            option_kind("retry-on-status: 429,503"),
+            OptionKind::RetryOnStatus(vec![429, 503])
+        );
+    }
+
+    #[test]
+    fn test_option_retry_on_status_error() {
+        assert_option_error("retry-on-status: ", 18, expecting("HTTP status code"));
+
+        let mut reader = Reader::new("retry-on-status: 429,");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 22
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_local_variable() {
+        assert_eq!(
+            option_kind("variable-local: id=1"),
+            OptionKind::LocalVariable(VariableDefinition {
+                name: "id".to_string(),
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 19), Pos::new(1, 19)),
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 20), Pos::new(1, 20)),
+                },
+                value: VariableValue::Number(Number::Integer(1)),
+            })
+        );
+
+        let mut reader = Reader::new("variable-local: id");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 19));
+    }
+
+    #[test]
+    fn test_variable_definition() {
+        let mut reader = Reader::new("a=1");
+        assert_eq!(
+            variable_definition(&mut reader).unwrap(),
+            VariableDefinition {
+                name: "a".to_string(),
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo {
+                        start: Pos { line: 1, column: 2 },
+                        end: Pos { line: 1, column: 2 },
+                    },
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo {
+                        start: Pos { line: 1, column: 3 },
+                        end: Pos { line: 1, column: 3 },
+                    },
+                },
+                value: VariableValue::Number(Number::Integer(1)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_variable_value() {
+        let mut reader = Reader::new("null");
+        assert_eq!(variable_value(&mut reader).unwrap(), VariableValue::Null);
+
+        let mut reader = Reader::new("true");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Bool(true)
+        );
+
+        let mut reader = Reader::new("1");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Number(Number::Integer(1))
+        );
+
+        let mut reader = Reader::new("toto");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::String(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "toto".to_string(),
+                    encoded: "toto".to_string(),
+                }],
+                source_info: SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos { line: 1, column: 5 },
+                },
+            })
+        );
+        let mut reader = Reader::new("\"123\"");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::String(Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: "123".to_string(),
+                    encoded: "123".to_string(),
+                }],
+                source_info: SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos { line: 1, column: 6 },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_variable_value_radix_integer() {
+        let mut reader = Reader::new("0xFF");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Number(Number::Integer(255))
+        );
+
+        let mut reader = Reader::new("0o17");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Number(Number::Integer(15))
+        );
+
+        let mut reader = Reader::new("42");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Number(Number::Integer(42))
+        );
+
+        // Floats are still parsed as numbers.
+        let mut reader = Reader::new("0.5");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Number(Number::Float(Float {
+                value: 0.5,
+                encoded: "0.5".to_string(),
+            }))
+        );
+
+        let mut reader = Reader::new("[0x10,0o10,10]");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::List(vec![
+                VariableValue::Number(Number::Integer(16)),
+                VariableValue::Number(Number::Integer(8)),
+                VariableValue::Number(Number::Integer(10)),
+            ])
+        );
+
+        let mut reader = Reader::new("0o9");
+        let error = radix_integer(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.pos, Pos::new(1, 3));
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "octal digits".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_variable_value_exponent() {
+        let floats = [
+            ("1e-6", 1e-6),
+            ("2.5E10", 2.5E10),
+            ("1e+3", 1e3),
+            ("-4.2e2", -420.0),
+        ];
+        for (text, value) in floats {
+            let mut reader = Reader::new(text);
+            assert_eq!(
+                variable_value(&mut reader).unwrap(),
+                VariableValue::Number(Number::Float(Float {
+                    value,
+                    encoded: text.to_string(),
+                }))
+            );
+        }
+
+        // Without exponent, integers and floats are unchanged.
+        let mut reader = Reader::new("10");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Number(Number::Integer(10))
+        );
+        let mut reader = Reader::new("10.0");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Number(Number::Float(Float {
+                value: 10.0,
+                encoded: "10.0".to_string(),
+            }))
+        );
+
+        let mut reader = Reader::new("[1e3,2]");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::List(vec![
+                VariableValue::Number(Number::Float(Float {
+                    value: 1000.0,
+                    encoded: "1e3".to_string(),
+                })),
+                VariableValue::Number(Number::Integer(2)),
+            ])
+        );
+
+        let mut reader = Reader::new("1e-");
+        let error = variable_number(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.pos, Pos::new(1, 4));
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "exponent digits".to_string()
+            }
+        );
+
+        let mut reader = Reader::new("1e999");
+        assert!(variable_number(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_variable_value_big_integer() {
+        for text in ["12345678901234567890", "-12345678901234567890"] {
+            let mut reader = Reader::new(text);
+            let value = variable_value(&mut reader).unwrap();
+            assert_eq!(
+                value,
+                VariableValue::Number(Number::BigInteger(text.to_string()))
+            );
+            assert_eq!(value.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_variable_value_list() {
+        let mut reader = Reader::new("[1,2,3]");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::List(vec![
+                VariableValue::Number(Number::Integer(1)),
+                VariableValue::Number(Number::Integer(2)),
+                VariableValue::Number(Number::Integer(3)),
+            ])
+        );
+        assert_eq!(reader.state.cursor, 7);
+
+        let mut reader = Reader::new("[]");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::List(vec![])
+        );
+
+        let mut reader = Reader::new("[ true, [null, []] ]");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::List(vec![
+                VariableValue::Bool(true),
+                VariableValue::List(vec![VariableValue::Null, VariableValue::List(vec![])]),
+            ])
+        );
+        assert_eq!(reader.state.cursor, 20);
+
+        let mut reader = Reader::new("[\"a\",1]");
+        let value = variable_value(&mut reader).unwrap();
+        assert_eq!(value.to_string(), "[\"a\",1]");
+    }
+
+    #[test]
+    fn test_variable_value_list_fallback_to_string() {
+        let mut reader = Reader::new("[abc");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::String(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "[abc".to_string(),
+                    encoded: "[abc".to_string(),
+                }],
+                source_info: SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos { line: 1, column: 5 },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_variable_value_object() {
+        let mut reader = Reader::new("{a:1,b:\"x\"}");
+        let value = variable_value(&mut reader).unwrap();
+        assert_eq!(
+            value,
+            VariableValue::Object(vec![
+                ("a".to_string(), VariableValue::Number(Number::Integer(1))),
+                (
+                    "b".to_string(),
+                    VariableValue::String(Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "x".to_string(),
+                            encoded: "x".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 11)),
+                    })
+                ),
+            ])
+        );
+        assert_eq!(value.to_string(), "{a:1,b:\"x\"}");
+
+        let mut reader = Reader::new("{}");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Object(vec![])
+        );
+    }
+
+    #[test]
+    fn test_variable_value_object_nested() {
+        let mut reader = Reader::new("{ ids: [1, 2], cfg: {debug: true} }");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Object(vec![
+                (
+                    "ids".to_string(),
+                    VariableValue::List(vec![
+                        VariableValue::Number(Number::Integer(1)),
+                        VariableValue::Number(Number::Integer(2)),
+                    ])
+                ),
+                (
+                    "cfg".to_string(),
+                    VariableValue::Object(vec![("debug".to_string(), VariableValue::Bool(true))])
+                ),
+            ])
+        );
+        assert_eq!(reader.state.cursor, 35);
+    }
+
+    #[test]
+    fn test_variable_value_object_error() {
+        let mut reader = Reader::new("{a 1}");
+        let error = variable_value(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 4));
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_variable_value_template_is_not_object() {
+        let mut reader = Reader::new("{{host}}");
+        let value = variable_value(&mut reader).unwrap();
+        assert!(matches!(
+            value,
+            VariableValue::String(Template {
+                delimiter: None,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_variable_value_file() {
+        let mut reader = Reader::new("file,secrets/token.txt;");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::File(
+                File {
+                    space0: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 6)),
+                    },
+                    filename: Template {
+                        delimiter: None,
+                        elements: vec![TemplateElement::String {
+                            value: "secrets/token.txt".to_string(),
+                            encoded: "secrets/token.txt".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 23)),
+                    },
+                    space1: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(1, 23), Pos::new(1, 23)),
+                    },
+                },
+                None,
+            )
+        );
+
+        // The trailing `;` is optional
+        let mut reader = Reader::new("file,secrets/token.txt # comment");
+        let value = variable_value(&mut reader).unwrap();
+        assert!(matches!(value, VariableValue::File(_, None)));
+        assert_eq!(value.to_string(), "file,secrets/token.txt;");
+        assert_eq!(reader.state.cursor, 22);
+
+        // Not a file value
+        for s in ["filename", "file,", "file,a b", "file,a.txt; b"] {
+            let mut reader = Reader::new(s);
+            let value = variable_value(&mut reader).unwrap();
+            assert!(matches!(value, VariableValue::String(_)));
+            assert_eq!(value.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_variable_value_env() {
+        let mut reader = Reader::new("env,HOME");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Env("HOME".to_string(), None)
+        );
+        assert_eq!(reader.state.cursor, 8);
+
+        // Not an env value
+        for s in ["environment", "env,", "env,foo bar", "env,a-b"] {
+            let mut reader = Reader::new(s);
+            let value = variable_value(&mut reader).unwrap();
+            assert!(matches!(value, VariableValue::String(_)));
+            assert_eq!(value.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_variable_value_default() {
+        let mut reader = Reader::new("env,PORT:8080");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Env(
+                "PORT".to_string(),
+                Some(Box::new(VariableValue::Number(Number::Integer(8080))))
+            )
+        );
+
+        let mut reader = Reader::new("env,HOST:localhost");
+        let value = variable_value(&mut reader).unwrap();
+        assert_eq!(value.to_string(), "env,HOST:localhost");
+
+        let mut reader = Reader::new("env,PORT");
+        assert_eq!(
+            variable_value(&mut reader).unwrap(),
+            VariableValue::Env("PORT".to_string(), None)
+        );
+
+        let mut reader = Reader::new("file,secrets/token.txt;:\"anonymous\"");
+        let value = variable_value(&mut reader).unwrap();
+        let VariableValue::File(_, Some(default)) = &value else {
+            panic!("expecting a file value with default, actual is {value:?}");
+        };
+        assert_eq!(default.to_string(), "anonymous");
+        assert_eq!(value.to_string(), "file,secrets/token.txt;:\"anonymous\"");
+
+        let mut reader = Reader::new("file,secrets/token.txt;");
+        let value = variable_value(&mut reader).unwrap();
+        assert!(matches!(value, VariableValue::File(_, None)));
+    }
+
+    #[test]
+    fn test_validate_all_undeclared_variable() {
+        let options = ["user-agent: hurl/{{version}}", "retry: {{retry_count}}"]
+            .iter()
+            .map(|s| parse(&mut Reader::new(s)).unwrap())
+            .collect::<Vec<_>>();
+        let declared = HashSet::from(["version".to_string()]);
+        let errors = validate_all(&options, &declared);
+        assert_eq!(
+            errors,
+            vec![Error {
+                pos: Pos::new(1, 10),
+                recoverable: true,
+                inner: ParseError::UndeclaredVariable {
+                    name: "retry_count".to_string()
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_all_declared_variables() {
+        let options = [
+            "variable: host=example.org",
+            "variable-local: id=1",
+            "referer: https://{{host}}/{{path}}",
+            "range: {{id}}-",
+            "insecure: {{insecure}}",
+        ]
+        .iter()
+        .map(|s| parse(&mut Reader::new(s)).unwrap())
+        .collect::<Vec<_>>();
+        let declared = HashSet::from(["path".to_string(), "insecure".to_string()]);
+        assert!(validate_all(&options, &declared).is_empty());
+    }
+
+    #[test]
+    fn test_check_option_value() {
+        assert!(check_option_value("insecure", "true").is_ok());
+        assert!(check_option_value("insecure", "{{insecure}}").is_ok());
+        assert!(check_option_value("retry", "-1").is_ok());
+        assert!(check_option_value("limit-rate", "100K").is_ok());
+    }
+
+    #[test]
+    fn test_check_option_value_error() {
+        assert!(check_option_value("insecure", "x").is_err());
+        assert!(check_option_value("insecure", "truex").is_err());
+        assert!(check_option_value("repeat", "-1").is_err());
+        assert_eq!(
+            check_option_value("insecur", "true"),
+            Err(ParseError::InvalidOption {
+                name: "insecur".to_string(),
+                suggestion: Some("insecure".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_option_multi_token_value_with_comment() {
+        for (input, name, value) in [
+            (
+                "resolve: api.test:443:127.0.0.1 # local override",
+                "resolve",
+                "api.test:443:127.0.0.1",
+            ),
+            (
+                "resolve: -api.test:443 # no more",
+                "resolve",
+                "-api.test:443",
+            ),
+            (
+                "connect-to: api.test:443:localhost:8443 # local server",
+                "connect-to",
+                "api.test:443:localhost:8443",
+            ),
+            (
+                "proxy: http://proxy.test:3128   # corporate proxy",
+                "proxy",
+                "http://proxy.test:3128",
+            ),
+        ] {
+            let mut reader = Reader::new(input);
+            let option = parse(&mut reader).unwrap();
+            assert_eq!(option.kind.name(), name);
+            assert_eq!(option.kind.value_as_str(), value);
+            let comment = option.line_terminator0.comment.unwrap();
+            assert_eq!(
+                format!("#{}", comment.value),
+                input[input.find('#').unwrap()..]
+            );
+            assert!(reader.is_eof());
+        }
+    }
+
+    #[test]
+    fn test_parse_entry_option() {
+        let option = parse_entry_option("retry: 5").unwrap();
+        assert_eq!(option.kind.name(), "retry");
+        assert_eq!(option.kind.value_as_str(), "5");
+
+        let option = parse_entry_option("  insecure: true # comment\n").unwrap();
+        assert_eq!(option.kind.name(), "insecure");
+        assert_eq!(option.kind.value_as_str(), "true");
+    }
+
+    #[test]
+    fn test_parse_entry_option_error() {
+        let error = parse_entry_option("insecur: true").unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 1));
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.message,
+            "the option name <insecur> is not valid. Did you mean insecure?"
+        );
+
+        let error = parse_entry_option("insecure: x").unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 11));
+        assert!(!error.recoverable);
+        assert_eq!(error.message, "expecting 'true|false'");
+        assert_eq!(error.to_string(), "1:11: expecting 'true|false'");
+
+        let error = parse_entry_option("retry: 5\nverbose: true").unwrap_err();
+        assert_eq!(error.pos, Pos::new(2, 1));
+        assert!(!error.recoverable);
+        assert_eq!(error.message, "expecting 'end of option'");
+
+        // Not an option at all.
+        let error = parse_entry_option("GET https://example.org").unwrap_err();
+        assert!(error.recoverable);
+    }
+
+    #[test]
+    fn test_parse_entry_option_strict() {
+        let strict = ParseOptions { strict: true };
+        let error = parse_entry_option_with("verbsoe: true", &strict).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 1));
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.message,
+            "the option name <verbsoe> is not valid. Did you mean verbose?"
+        );
+
+        let lenient = ParseOptions { strict: false };
+        let error = parse_entry_option_with("verbsoe: true", &lenient).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 1));
+        assert!(error.recoverable);
+        assert_eq!(
+            error.message,
+            "the option name <verbsoe> is not valid. Did you mean verbose?"
+        );
+
+        // Only unknown options are affected by the strict mode.
+        let error = parse_entry_option_with("verbose: x", &lenient).unwrap_err();
+        assert!(!error.recoverable);
+        let option = parse_entry_option_with("verbose: true", &lenient).unwrap();
+        assert_eq!(option.kind.name(), "verbose");
+    }
+
+    #[test]
+    fn test_format_option() {
+        let format = |s: &str| format_option(&parse(&mut Reader::new(s)).unwrap());
+        assert_eq!(format("insecure :true"), "insecure: true");
+        assert_eq!(format("insecure:true\n"), "insecure: true\n");
+        assert_eq!(format("retry   :    5"), "retry: 5");
+        assert_eq!(
+            format("  location:true  # follow\n"),
+            "  location: true  # follow\n"
+        );
+        assert_eq!(
+            format("# comment\nuser-agent:hurl/\\#1 {{version}}\n"),
+            "# comment\nuser-agent: hurl/\\#1 {{version}}\n"
+        );
+        assert_eq!(format("data-binary:@data.bin"), "data-binary: @data.bin");
+        assert_eq!(
+            format("resolve:  -example.com:443"),
+            "resolve: -example.com:443"
+        );
+        assert_eq!(format("noproxy:\"localhost\""), "noproxy: \"localhost\"");
+        assert_eq!(format("variable:a=\\#b"), "variable: a=\\#b");
+
+        // Formatting is idempotent.
+        for s in [
+            "insecure :true",
+            "verbose:2 # level\n",
+            "variable :  list=[1,\"a\"]",
+        ] {
+            let formatted = format(s);
+            assert_eq!(format(&formatted), formatted);
+        }
+    }
+
+    #[test]
+    fn test_option_kind_display() {
+        let samples = [
+            // Boolean
+            "insecure: true",
+            "compressed: {{compress}}",
+            // Natural
+            "max-redirs: 10",
+            "delay: {{delay}}",
+            // Filename
+            "cacert: ca\\ file.pem",
+            "data-binary: @data.bin",
+            // Template
+            "user-agent: hurl/\\#1 {{version}}",
+            "noproxy: \"localhost,.internal\"",
+            "resolve: -example.com:443",
+            // Retry
+            "retry: 5",
+            "retry: -1",
+            "connect-retries: {{retries}}",
+            // Other values
+            "limit-rate: 100K",
+            "verbose: 2",
+            "variable: ids=[1,2]",
+        ];
+        for sample in samples {
+            let kind = parse(&mut Reader::new(sample)).unwrap().kind;
+            assert_eq!(kind.to_string(), sample);
+            let reparsed = parse(&mut Reader::new(&kind.to_string())).unwrap().kind;
+            assert_eq!(reparsed.to_string(), kind.to_string());
+            assert_eq!(reparsed.value_as_str(), kind.value_as_str());
+        }
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let options = [
+            "insecure: true",
+            "location: true",
+            "variable: a=1",
+            "variable: b=2",
+            "variable-local: c=3",
+            "variable-local: d=4",
+            "resolve: example.com:443:127.0.0.1",
+            "resolve: example.org:443:127.0.0.1",
+        ]
+        .iter()
+        .map(|s| parse(&mut Reader::new(s)).unwrap())
+        .collect::<Vec<_>>();
+        assert!(validate_options(&options).is_empty());
+    }
+
+    #[test]
+    fn test_validate_options_query() {
+        let options = ["query: page=1", "query: sort=asc"]
+            .iter()
+            .map(|s| parse(&mut Reader::new(s)).unwrap())
+            .collect::<Vec<_>>();
+        assert!(validate_options(&options).is_empty());
+    }
+
+    #[test]
+    fn test_validate_options_duplicate() {
+        let options = ["insecure: true", "verbose: true", "  insecure: false"]
+            .iter()
+            .map(|s| parse(&mut Reader::new(s)).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            validate_options(&options),
+            vec![Error {
+                pos: Pos::new(1, 3),
+                recoverable: true,
+                inner: ParseError::DuplicateOption {
+                    name: "insecure".to_string()
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_ip_version() {
+        let options = [
+            "ipv4: true",
+            "ip-version: 4",
+            "ipv6: false",
+            "ip-version: auto",
+        ]
+        .iter()
+        .map(|s| parse(&mut Reader::new(s)).unwrap())
+        .collect::<Vec<_>>();
+        assert!(check_ip_version(&options).is_ok());
+    }
+
+    #[test]
+    fn test_check_ip_version_conflict() {
+        let options = ["ipv4: true", "verbose: true", "  ip-version: 6"]
+            .iter()
+            .map(|s| parse(&mut Reader::new(s)).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            check_ip_version(&options),
+            Err(Error {
+                pos: Pos::new(1, 3),
+                recoverable: false,
+                inner: ParseError::ConflictingOption {
+                    name: "ip-version".to_string(),
+                    other: "ipv4".to_string()
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_connect_to() {
+        let options = [
+            "connect-to: example.com:443:host-47.example.com:443",
+            "connect-to: example.com:80:host-47.example.com:8080",
+        ]
+        .iter()
+        .map(|s| parse(&mut Reader::new(s)).unwrap())
+        .collect::<Vec<_>>();
+        assert!(validate_connect_to(&options).is_empty());
+    }
+
+    #[test]
+    fn test_validate_connect_to_shadowed() {
+        let options = [
+            "connect-to: example.com:443:host-47.example.com:443",
+            "connect-to: example.com:443:host-48.example.com:443",
+        ]
+        .iter()
+        .map(|s| parse(&mut Reader::new(s)).unwrap())
+        .collect::<Vec<_>>();
+        assert_eq!(
+            validate_connect_to(&options),
+            vec![Error {
+                pos: Pos::new(1, 13),
+                recoverable: true,
+                inner: ParseError::ShadowedConnectTo {
+                    source: "example.com:443".to_string()
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sort_options() {
+        let mut options = [
+            "variable: b=2",
+            "retry: 3",
+            "insecure: true",
+            "variable: a=1",
+            "connect-to: example.com:443:host-47.example.com:443",
+            "variable: c=3",
+        ]
+        .iter()
+        .map(|s| parse(&mut Reader::new(s)).unwrap())
+        .collect::<Vec<_>>();
+        sort_options(&mut options);
+        let values = options
+            .iter()
+            .map(|option| format!("{}: {}", option.kind.name(), option.kind.value_as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                "connect-to: example.com:443:host-47.example.com:443",
+                "insecure: true",
+                "retry: 3",
+                "variable: b=2",
+                "variable: a=1",
+                "variable: c=3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_option_verbose_level() {
+        for (text, level) in [("verbose: 0", 0), ("verbose: 1", 1), ("verbose: 2", 2)] {
+            let kind = option_kind(text);
+            assert_eq!(kind, OptionKind::VerboseLevel(level));
+            assert_eq!(kind.name(), "verbose");
+        }
+
+        assert_eq!(
+            option_kind("verbose: true"),
+            OptionKind::Verbose(BooleanOption::Literal(true))
+        );
+    }
+
+    #[test]
+    fn test_option_verbose_level_error() {
+        assert_option_error("verbose: 3", 10, expecting("verbose level 0, 1 or 2"));
     }
 }
@@ -150,6 +150,7 @@ fn section_value_asserts(reader: &mut Reader) -> ParseResult<SectionValue> {
 
 fn section_value_options(reader: &mut Reader) -> ParseResult<SectionValue> {
     let options = zero_or_more(option::parse, reader)?;
+    option::check_ip_version(&options)?;
     Ok(SectionValue::Options(options))
 }
 
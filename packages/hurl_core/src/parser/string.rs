@@ -27,12 +27,24 @@ use crate::parser::{template, ParseResult};
 ///    the string does not contain trailing space
 /// 2- templatize
 pub fn unquoted_template(reader: &mut Reader) -> ParseResult<Template> {
+    unquoted_template_until(reader, |_| false)
+}
+
+/// Parses an unquoted template like [`unquoted_template`], stopping also before the position
+/// where `is_end` returns true.
+pub fn unquoted_template_until(
+    reader: &mut Reader,
+    is_end: fn(&Reader) -> bool,
+) -> ParseResult<Template> {
     let start = reader.state;
     let mut chars = vec![];
     let mut spaces = vec![];
     let mut end = start;
     loop {
         let pos = reader.state.pos;
+        if is_end(reader) {
+            break;
+        }
         match any_char(vec!['#'], reader) {
             Err(e) => {
                 if e.recoverable {
@@ -312,6 +324,23 @@ mod tests {
         assert_eq!(reader.state.cursor, 4);
     }
 
+    #[test]
+    fn test_unquoted_template_until() {
+        let mut reader = Reader::new("ab;c");
+        assert_eq!(
+            unquoted_template_until(&mut reader, |reader| reader.peek_n(1) == ";").unwrap(),
+            Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "ab".to_string(),
+                    encoded: "ab".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 3)),
+            }
+        );
+        assert_eq!(reader.state.cursor, 2);
+    }
+
     #[test]
     fn test_unquoted_template_hello_world() {
         let mut reader = Reader::new("hello\\u{20}{{name}}!");
@@ -280,39 +280,111 @@ impl ToJson for EntryOption {
 
         let name = "value".to_string();
         let value = match &self.kind {
+            OptionKind::AbortOnError(value) => value.to_json(),
+            OptionKind::Accept(value) => JValue::String(value.to_string()),
+            OptionKind::AcceptEncoding(value) => JValue::String(value.to_string()),
+            OptionKind::AwsRegion(value) => JValue::String(value.to_string()),
+            OptionKind::AwsService(value) => JValue::String(value.to_string()),
             OptionKind::AwsSigV4(value) => JValue::String(value.to_string()),
+            OptionKind::BodyFile(filename) => JValue::String(filename.to_string()),
             OptionKind::CaCertificate(filename) => JValue::String(filename.to_string()),
+            OptionKind::CertType(value) => JValue::String(value.to_string()),
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
             OptionKind::Compressed(value) => value.to_json(),
+            OptionKind::ConnectRetries(value) => value.to_json(),
+            OptionKind::ConnectTimeout(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
+            OptionKind::ContentLength(value) => value.to_json(),
+            OptionKind::ContentType(value) => JValue::String(value.to_string()),
+            OptionKind::ContinueOnError(value) => value.to_json(),
+            OptionKind::Crlf(value) => value.to_json(),
+            OptionKind::DataBinary(filename) => JValue::String(filename.to_string()),
             OptionKind::Delay(value) => value.to_json(),
+            OptionKind::DnsCacheTtl(value) => value.to_json(),
+            OptionKind::Expect100Timeout(value) => value.to_json(),
+            OptionKind::FailWithBody(value) => value.to_json(),
             OptionKind::FollowLocation(value) => value.to_json(),
             OptionKind::FollowLocationTrusted(value) => value.to_json(),
+            OptionKind::Form(value) => JValue::String(value.to_string()),
+            OptionKind::FreshConnect(value) => value.to_json(),
+            OptionKind::GlobOff(value) => value.to_json(),
+            OptionKind::HappyEyeballsTimeout(value) => value.to_json(),
+            OptionKind::HaproxyProtocol(value) => value.to_json(),
             OptionKind::Http10(value) => value.to_json(),
             OptionKind::Http11(value) => value.to_json(),
             OptionKind::Http2(value) => value.to_json(),
             OptionKind::Http3(value) => value.to_json(),
+            OptionKind::HttpVersion(value) => JValue::String(value.to_string()),
             OptionKind::Insecure(value) => value.to_json(),
             OptionKind::IpV4(value) => value.to_json(),
             OptionKind::IpV6(value) => value.to_json(),
+            OptionKind::Json(value) => JValue::String(value.to_string()),
+            OptionKind::IpVersion(value) => JValue::String(value.to_string()),
+            OptionKind::KeyPassword(value) => JValue::String(value.to_string()),
+            OptionKind::KeyType(value) => JValue::String(value.to_string()),
+            OptionKind::LimitRate(value) => JValue::String(value.to_string()),
+            OptionKind::LoginOptions(value) => JValue::String(value.to_string()),
+            OptionKind::MaxFileSize(value) => value.to_json(),
+            OptionKind::MaxHeaderSize(value) => JValue::String(value.to_string()),
             OptionKind::MaxRedirect(value) => value.to_json(),
+            OptionKind::MaxTime(value) => value.to_json(),
             OptionKind::NetRc(value) => value.to_json(),
             OptionKind::NetRcFile(filename) => JValue::String(filename.to_string()),
             OptionKind::NetRcOptional(value) => value.to_json(),
+            OptionKind::NoBuffer(value) => value.to_json(),
+            OptionKind::NoProxy(value) => JValue::String(value.to_string()),
             OptionKind::Output(filename) => JValue::String(filename.to_string()),
+            OptionKind::Parallel(value) => value.to_json(),
             OptionKind::PathAsIs(value) => value.to_json(),
+            OptionKind::Post301(value) => value.to_json(),
+            OptionKind::Post302(value) => value.to_json(),
+            OptionKind::Post303(value) => value.to_json(),
             OptionKind::Proxy(value) => JValue::String(value.to_string()),
+            OptionKind::ProxyCaCert(filename) => JValue::String(filename.to_string()),
+            OptionKind::ProxyInsecure(value) => value.to_json(),
+            OptionKind::ProxyUser(value) => JValue::String(value.to_string()),
+            OptionKind::Query(value) => JValue::String(value.to_string()),
+            OptionKind::Range(value) => JValue::String(value.to_string()),
+            OptionKind::RawResponse(value) => value.to_json(),
+            OptionKind::Referer(value) => JValue::String(value.to_string()),
+            OptionKind::RemoveResolve(value) => JValue::String(format!("-{value}")),
+            OptionKind::Repeat(value) => value.to_json(),
+            OptionKind::RequestMethod(value) => JValue::String(value.to_string()),
             OptionKind::Resolve(value) => JValue::String(value.to_string()),
             OptionKind::Retry(value) => value.to_json(),
+            OptionKind::RetryDeadline(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
+            OptionKind::RetryJitter(value) => value.to_json(),
+            OptionKind::RetryOn(value) => JValue::String(value.to_string()),
+            OptionKind::RetryOnStatus(value) => JValue::List(
+                value
+                    .iter()
+                    .map(|c| JValue::Number(c.to_string()))
+                    .collect(),
+            ),
+            OptionKind::SendFragment(value) => value.to_json(),
             OptionKind::Skip(value) => value.to_json(),
+            OptionKind::SpeedLimit(value) => value.to_json(),
+            OptionKind::SpeedTime(value) => value.to_json(),
+            OptionKind::SshPrivateKey(filename) => JValue::String(filename.to_string()),
+            OptionKind::SshPublicKey(filename) => JValue::String(filename.to_string()),
+            OptionKind::StopOnFailure(value) => value.to_json(),
+            OptionKind::TcpKeepAlive(value) => value.to_json(),
+            OptionKind::TcpKeepIdle(value) => value.to_json(),
+            OptionKind::TcpKeepIntvl(value) => value.to_json(),
+            OptionKind::TcpNoDelay(value) => value.to_json(),
+            OptionKind::TlsMax(value) => JValue::String(value.to_string()),
+            OptionKind::TraceBody(value) => JValue::String(value.to_string()),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
+            OptionKind::Url(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
-            OptionKind::Variable(value) => {
+            OptionKind::UserAgent(value) => JValue::String(value.to_string()),
+            OptionKind::Variable(value) | OptionKind::LocalVariable(value) => {
                 JValue::String(format!("{}={}", value.name, value.value))
             }
             OptionKind::Verbose(value) => value.to_json(),
+            OptionKind::VerboseLevel(value) => JValue::Number(value.to_string()),
             OptionKind::VeryVerbose(value) => value.to_json(),
         };
         attributes.push((name, value));
@@ -339,6 +411,15 @@ impl ToJson for NaturalOption {
     }
 }
 
+impl ToJson for DurationOption {
+    fn to_json(&self) -> JValue {
+        match self {
+            DurationOption::Literal(value) => JValue::Number(value.millis.to_string()),
+            DurationOption::Expression(expr) => expr.to_json(),
+        }
+    }
+}
+
 impl ToJson for RetryOption {
     fn to_json(&self) -> JValue {
         match self {
@@ -358,6 +439,15 @@ impl ToJson for Retry {
     }
 }
 
+impl ToJson for ContentLength {
+    fn to_json(&self) -> JValue {
+        match self {
+            ContentLength::Fixed(value) => JValue::Number(value.to_string()),
+            _ => JValue::String(self.to_string()),
+        }
+    }
+}
+
 impl ToJson for Capture {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![
@@ -879,37 +879,105 @@ impl Tokenizable for EntryOption {
 impl Tokenizable for OptionKind {
     fn tokenize(&self) -> Vec<Token> {
         match self {
+            OptionKind::AbortOnError(value) => value.tokenize(),
+            OptionKind::Accept(value) => value.tokenize(),
+            OptionKind::AcceptEncoding(value) => value.tokenize(),
+            OptionKind::AwsRegion(value) => value.tokenize(),
+            OptionKind::AwsService(value) => value.tokenize(),
             OptionKind::AwsSigV4(value) => value.tokenize(),
+            OptionKind::BodyFile(filename) => filename.tokenize(),
             OptionKind::CaCertificate(filename) => filename.tokenize(),
+            OptionKind::CertType(value) => vec![Token::String(value.to_string())],
             OptionKind::ClientCert(filename) => filename.tokenize(),
             OptionKind::ClientKey(filename) => filename.tokenize(),
             OptionKind::Compressed(value) => value.tokenize(),
+            OptionKind::ConnectRetries(value) => value.tokenize(),
+            OptionKind::ConnectTimeout(value) => value.tokenize(),
             OptionKind::ConnectTo(value) => value.tokenize(),
+            OptionKind::ContentLength(value) => value.tokenize(),
+            OptionKind::ContentType(value) => value.tokenize(),
+            OptionKind::ContinueOnError(value) => value.tokenize(),
+            OptionKind::Crlf(value) => value.tokenize(),
+            OptionKind::DataBinary(filename) => tokenize_data_binary(filename),
             OptionKind::Delay(value) => value.tokenize(),
+            OptionKind::DnsCacheTtl(value) => value.tokenize(),
+            OptionKind::Expect100Timeout(value) => value.tokenize(),
+            OptionKind::FailWithBody(value) => value.tokenize(),
             OptionKind::FollowLocation(value) => value.tokenize(),
             OptionKind::FollowLocationTrusted(value) => value.tokenize(),
+            OptionKind::Form(value) => value.tokenize(),
+            OptionKind::FreshConnect(value) => value.tokenize(),
+            OptionKind::GlobOff(value) => value.tokenize(),
+            OptionKind::HappyEyeballsTimeout(value) => value.tokenize(),
+            OptionKind::HaproxyProtocol(value) => value.tokenize(),
             OptionKind::Http10(value) => value.tokenize(),
             OptionKind::Http11(value) => value.tokenize(),
             OptionKind::Http2(value) => value.tokenize(),
             OptionKind::Http3(value) => value.tokenize(),
+            OptionKind::HttpVersion(value) => vec![Token::Number(value.to_string())],
             OptionKind::Insecure(value) => value.tokenize(),
             OptionKind::IpV4(value) => value.tokenize(),
             OptionKind::IpV6(value) => value.tokenize(),
+            OptionKind::Json(value) => value.tokenize(),
+            OptionKind::IpVersion(value) => vec![Token::String(value.to_string())],
+            OptionKind::KeyPassword(value) => value.tokenize(),
+            OptionKind::KeyType(value) => vec![Token::String(value.to_string())],
+            OptionKind::LimitRate(value) => vec![Token::Number(value.to_string())],
+            OptionKind::LoginOptions(value) => value.tokenize(),
+            OptionKind::MaxFileSize(value) => value.tokenize(),
+            OptionKind::MaxHeaderSize(value) => vec![Token::Number(value.to_string())],
             OptionKind::MaxRedirect(value) => value.tokenize(),
+            OptionKind::MaxTime(value) => value.tokenize(),
             OptionKind::NetRc(value) => value.tokenize(),
             OptionKind::NetRcFile(filename) => filename.tokenize(),
             OptionKind::NetRcOptional(value) => value.tokenize(),
+            OptionKind::NoBuffer(value) => value.tokenize(),
+            OptionKind::NoProxy(value) => value.tokenize(),
             OptionKind::Output(filename) => filename.tokenize(),
+            OptionKind::Parallel(value) => value.tokenize(),
             OptionKind::PathAsIs(value) => value.tokenize(),
+            OptionKind::Post301(value) => value.tokenize(),
+            OptionKind::Post302(value) => value.tokenize(),
+            OptionKind::Post303(value) => value.tokenize(),
             OptionKind::Proxy(value) => value.tokenize(),
+            OptionKind::ProxyCaCert(filename) => filename.tokenize(),
+            OptionKind::ProxyInsecure(value) => value.tokenize(),
+            OptionKind::ProxyUser(value) => value.tokenize(),
+            OptionKind::Query(value) => value.tokenize(),
+            OptionKind::Range(value) => value.tokenize(),
+            OptionKind::RawResponse(value) => value.tokenize(),
+            OptionKind::Referer(value) => value.tokenize(),
+            OptionKind::RemoveResolve(value) => tokenize_remove_resolve(value),
+            OptionKind::Repeat(value) => value.tokenize(),
+            OptionKind::RequestMethod(value) => value.tokenize(),
             OptionKind::Resolve(value) => value.tokenize(),
             OptionKind::Retry(value) => value.tokenize(),
+            OptionKind::RetryDeadline(value) => value.tokenize(),
             OptionKind::RetryInterval(value) => value.tokenize(),
+            OptionKind::RetryJitter(value) => value.tokenize(),
+            OptionKind::RetryOn(value) => vec![Token::String(value.to_string())],
+            OptionKind::RetryOnStatus(value) => tokenize_status_codes(value),
+            OptionKind::SendFragment(value) => value.tokenize(),
             OptionKind::Skip(value) => value.tokenize(),
+            OptionKind::SpeedLimit(value) => value.tokenize(),
+            OptionKind::SpeedTime(value) => value.tokenize(),
+            OptionKind::SshPrivateKey(filename) => filename.tokenize(),
+            OptionKind::SshPublicKey(filename) => filename.tokenize(),
+            OptionKind::StopOnFailure(value) => value.tokenize(),
+            OptionKind::TcpKeepAlive(value) => value.tokenize(),
+            OptionKind::TcpKeepIdle(value) => value.tokenize(),
+            OptionKind::TcpKeepIntvl(value) => value.tokenize(),
+            OptionKind::TcpNoDelay(value) => value.tokenize(),
+            OptionKind::TlsMax(value) => vec![Token::Number(value.to_string())],
+            OptionKind::TraceBody(value) => vec![Token::String(value.to_string())],
             OptionKind::UnixSocket(value) => value.tokenize(),
+            OptionKind::Url(value) => value.tokenize(),
             OptionKind::User(value) => value.tokenize(),
+            OptionKind::UserAgent(value) => value.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
+            OptionKind::LocalVariable(value) => value.tokenize(),
             OptionKind::Verbose(value) => value.tokenize(),
+            OptionKind::VerboseLevel(value) => vec![Token::Number(value.to_string())],
             OptionKind::VeryVerbose(value) => value.tokenize(),
         }
     }
@@ -933,6 +1001,15 @@ impl Tokenizable for NaturalOption {
     }
 }
 
+impl Tokenizable for DurationOption {
+    fn tokenize(&self) -> Vec<Token> {
+        match self {
+            DurationOption::Literal(value) => vec![Token::Number(value.to_string())],
+            DurationOption::Expression(expr) => expr.tokenize(),
+        }
+    }
+}
+
 impl Tokenizable for RetryOption {
     fn tokenize(&self) -> Vec<Token> {
         match self {
@@ -952,6 +1029,39 @@ impl Tokenizable for Retry {
     }
 }
 
+impl Tokenizable for ContentLength {
+    fn tokenize(&self) -> Vec<Token> {
+        match self {
+            ContentLength::Auto => vec![Token::String("auto".to_string())],
+            ContentLength::Omit => vec![Token::String("omit".to_string())],
+            ContentLength::Fixed(n) => vec![Token::Number(n.to_string())],
+        }
+    }
+}
+
+fn tokenize_status_codes(codes: &[u32]) -> Vec<Token> {
+    let mut tokens: Vec<Token> = vec![];
+    for (i, code) in codes.iter().enumerate() {
+        if i > 0 {
+            tokens.push(Token::Keyword(",".to_string()));
+        }
+        tokens.push(Token::Number(code.to_string()));
+    }
+    tokens
+}
+
+fn tokenize_data_binary(filename: &Template) -> Vec<Token> {
+    let mut tokens: Vec<Token> = vec![Token::Keyword("@".to_string())];
+    tokens.append(&mut filename.tokenize());
+    tokens
+}
+
+fn tokenize_remove_resolve(value: &Template) -> Vec<Token> {
+    let mut tokens: Vec<Token> = vec![Token::Keyword("-".to_string())];
+    tokens.append(&mut value.tokenize());
+    tokens
+}
+
 impl Tokenizable for VariableDefinition {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![Token::String(self.name.clone())];
@@ -970,7 +1080,57 @@ impl Tokenizable for VariableValue {
             VariableValue::Bool(v) => vec![Token::Boolean(v.to_string())],
             VariableValue::Number(v) => vec![Token::Number(v.to_string())],
             VariableValue::String(v) => v.tokenize(),
+            VariableValue::List(values) => {
+                let mut tokens = vec![Token::CodeDelimiter("[".to_string())];
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        tokens.push(Token::CodeDelimiter(",".to_string()));
+                    }
+                    tokens.append(&mut value.tokenize());
+                }
+                tokens.push(Token::CodeDelimiter("]".to_string()));
+                tokens
+            }
+            VariableValue::Object(entries) => {
+                let mut tokens = vec![Token::CodeDelimiter("{".to_string())];
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        tokens.push(Token::CodeDelimiter(",".to_string()));
+                    }
+                    tokens.push(Token::String(key.clone()));
+                    tokens.push(Token::Colon(":".to_string()));
+                    tokens.append(&mut value.tokenize());
+                }
+                tokens.push(Token::CodeDelimiter("}".to_string()));
+                tokens
+            }
+            VariableValue::File(file, default) => {
+                let mut tokens = file.tokenize();
+                tokens.append(&mut default_tokens(default));
+                tokens
+            }
+            VariableValue::Env(name, default) => {
+                let mut tokens = vec![
+                    Token::Keyword("env".to_string()),
+                    Token::CodeDelimiter(",".to_string()),
+                    Token::String(name.clone()),
+                ];
+                tokens.append(&mut default_tokens(default));
+                tokens
+            }
+        }
+    }
+}
+
+/// Returns the tokens of the optional `:default` suffix of a variable value.
+fn default_tokens(default: &Option<Box<VariableValue>>) -> Vec<Token> {
+    match default {
+        Some(default) => {
+            let mut tokens = vec![Token::Colon(":".to_string())];
+            tokens.append(&mut default.tokenize());
+            tokens
         }
+        None => vec![],
     }
 }
 